@@ -1,7 +1,10 @@
 use crate::{async_transfer, traits::*, AsyncHeapRb};
-use core::sync::atomic::{AtomicUsize, Ordering};
-use futures::task::{noop_waker_ref, AtomicWaker};
-use std::{vec, vec::Vec};
+use core::{
+    sync::atomic::{AtomicUsize, Ordering},
+    task::Context,
+};
+use futures::task::{self, noop_waker_ref, ArcWake, AtomicWaker};
+use std::{sync::Arc, vec, vec::Vec};
 
 #[test]
 fn atomic_waker() {
@@ -168,3 +171,30 @@ fn wait() {
         },
     );
 }
+
+#[test]
+fn poll_reserve() {
+    struct CountWake(AtomicUsize);
+    impl ArcWake for CountWake {
+        fn wake_by_ref(arc_self: &Arc<Self>) {
+            arc_self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let (mut prod, mut cons) = AsyncHeapRb::<usize>::new(2).split();
+
+    let mut cx = Context::from_waker(noop_waker_ref());
+    assert!(prod.poll_reserve(2, &mut cx).is_ready());
+    prod.try_push(0).unwrap();
+    assert!(prod.poll_reserve(2, &mut cx).is_pending());
+
+    let wake = Arc::new(CountWake(AtomicUsize::new(0)));
+    let waker = task::waker(wake.clone());
+    let mut cx = Context::from_waker(&waker);
+    assert!(prod.poll_reserve(2, &mut cx).is_pending());
+    assert_eq!(wake.0.load(Ordering::SeqCst), 0);
+
+    assert_eq!(cons.try_pop(), Some(0));
+    assert_eq!(wake.0.load(Ordering::SeqCst), 1);
+    assert!(prod.poll_reserve(2, &mut cx).is_ready());
+}
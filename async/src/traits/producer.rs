@@ -44,6 +44,21 @@ pub trait AsyncProducer: AsyncObserver + Producer {
         }
     }
 
+    /// Polls for the buffer to have at least `count` free places for items or to close.
+    ///
+    /// If there is not enough free places then `cx` waker is registered to be woken when the consumer removes items.
+    ///
+    /// Panics if `count` is greater than buffer capacity.
+    fn poll_reserve(&self, count: usize, cx: &mut Context<'_>) -> Poll<()> {
+        debug_assert!(count <= self.capacity().get());
+        self.register_read_waker(cx.waker());
+        if count <= self.vacant_len() || self.is_closed() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+
     /// Wait for the buffer to have at least `count` free places for items or to close.
     ///
     /// Panics if `count` is greater than buffer capacity.
@@ -191,13 +206,7 @@ impl<'a, A: AsyncProducer> Future for WaitVacantFuture<'a, A> {
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         assert!(!self.done);
-        self.owner.register_read_waker(cx.waker());
-        let closed = self.owner.is_closed();
-        if self.count <= self.owner.vacant_len() || closed {
-            Poll::Ready(())
-        } else {
-            Poll::Pending
-        }
+        self.owner.poll_reserve(self.count, cx)
     }
 }
 
@@ -1,4 +1,8 @@
+#[cfg(feature = "std")]
+use crate::sync::StdInstant;
 use crate::traits::{BlockingConsumer, BlockingProducer};
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
 use core::time::Duration;
 use ringbuf::{
     delegate_consumer, delegate_observer, delegate_producer, impl_consumer_traits, impl_producer_traits,
@@ -93,6 +97,34 @@ where
     }
 }
 
+#[cfg(feature = "std")]
+impl<R: RbRef> BlockingCons<R>
+where
+    R::Target: BlockingConsumer,
+{
+    /// Removes items from the ring buffer until `max` items are collected or `deadline` is reached.
+    ///
+    /// Blocks waiting for new items in between, and wakes up as soon as an item is pushed.
+    /// Items already stored in the ring buffer are taken even if `deadline` has passed.
+    ///
+    /// Returns collected items in order, there may be fewer than `max` of them if deadline is reached.
+    pub fn drain_until(&mut self, deadline: StdInstant, max: usize) -> Vec<<Self as Observer>::Item> {
+        let mut items = Vec::new();
+        loop {
+            let rest = max - items.len();
+            self.pop_into_vec(&mut items, rest);
+            if items.len() == max {
+                break;
+            }
+            let timeout = deadline.saturating_duration_since(StdInstant::now());
+            if timeout.is_zero() || !self.wait_occupied(1, Some(timeout)) {
+                break;
+            }
+        }
+        items
+    }
+}
+
 impl_producer_traits!(BlockingProd<R: RbRef>);
 impl_consumer_traits!(BlockingCons<R: RbRef>);
 
@@ -9,6 +9,7 @@ extern crate std;
 mod alias;
 pub mod halves;
 pub mod rb;
+pub mod spin;
 pub mod sync;
 pub mod traits;
 
@@ -18,3 +19,4 @@ mod tests;
 pub use alias::*;
 pub use halves::{BlockingCons, BlockingProd};
 pub use rb::BlockingRb;
+pub use spin::{SpinCons, SpinProd};
@@ -0,0 +1,106 @@
+use core::hint::spin_loop;
+use ringbuf::{
+    delegate_consumer, delegate_observer, delegate_producer,
+    traits::{Consumer, Observer, Producer},
+};
+
+/// Producer wrapper that busy-waits for vacant space.
+///
+/// Does not require `std`, so it can be used on bare-metal targets where no blocking primitives are available.
+pub struct SpinProd<P: Producer> {
+    base: P,
+}
+
+/// Consumer wrapper that busy-waits for items.
+///
+/// Does not require `std`, so it can be used on bare-metal targets where no blocking primitives are available.
+pub struct SpinCons<C: Consumer> {
+    base: C,
+}
+
+impl<P: Producer> SpinProd<P> {
+    pub fn new(base: P) -> Self {
+        Self { base }
+    }
+    pub fn into_inner(self) -> P {
+        self.base
+    }
+    fn base(&self) -> &P {
+        &self.base
+    }
+    fn base_mut(&mut self) -> &mut P {
+        &mut self.base
+    }
+
+    /// Pushes an item spinning until the ring buffer has vacant space.
+    pub fn push_spin(&mut self, mut item: P::Item) {
+        while let Err(rejected) = self.base.try_push(item) {
+            item = rejected;
+            spin_loop();
+        }
+    }
+    /// Pushes an item spinning at most `limit` times while the ring buffer is full.
+    ///
+    /// If the ring buffer is still full after that returns an `Err` containing the item.
+    pub fn push_spin_limit(&mut self, mut item: P::Item, limit: usize) -> Result<(), P::Item> {
+        for _ in 0..limit {
+            match self.base.try_push(item) {
+                Ok(()) => return Ok(()),
+                Err(rejected) => item = rejected,
+            }
+            spin_loop();
+        }
+        self.base.try_push(item)
+    }
+}
+
+impl<C: Consumer> SpinCons<C> {
+    pub fn new(base: C) -> Self {
+        Self { base }
+    }
+    pub fn into_inner(self) -> C {
+        self.base
+    }
+    fn base(&self) -> &C {
+        &self.base
+    }
+    fn base_mut(&mut self) -> &mut C {
+        &mut self.base
+    }
+
+    /// Pops an item spinning until the ring buffer is not empty.
+    pub fn pop_spin(&mut self) -> C::Item {
+        loop {
+            if let Some(item) = self.base.try_pop() {
+                break item;
+            }
+            spin_loop();
+        }
+    }
+    /// Pops an item spinning at most `limit` times while the ring buffer is empty.
+    ///
+    /// Returns `None` if the ring buffer is still empty after that.
+    pub fn pop_spin_limit(&mut self, limit: usize) -> Option<C::Item> {
+        for _ in 0..limit {
+            if let Some(item) = self.base.try_pop() {
+                return Some(item);
+            }
+            spin_loop();
+        }
+        self.base.try_pop()
+    }
+}
+
+impl<P: Producer> Observer for SpinProd<P> {
+    delegate_observer!(P, Self::base);
+}
+impl<P: Producer> Producer for SpinProd<P> {
+    delegate_producer!(Self::base, Self::base_mut);
+}
+
+impl<C: Consumer> Observer for SpinCons<C> {
+    delegate_observer!(C, Self::base);
+}
+impl<C: Consumer> Consumer for SpinCons<C> {
+    delegate_consumer!(Self::base, Self::base_mut);
+}
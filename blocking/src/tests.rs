@@ -1,5 +1,13 @@
-use crate::{traits::*, BlockingHeapRb};
-use std::{iter::once, string::String, thread, time::Duration, vec, vec::Vec};
+use crate::{traits::*, BlockingHeapRb, SpinCons, SpinProd};
+use ringbuf::HeapRb;
+use std::{
+    iter::once,
+    string::String,
+    thread,
+    time::{Duration, Instant},
+    vec,
+    vec::Vec,
+};
 
 const THE_BOOK_FOREWORD: &str = r#"
 It wasn't always so clear, but the Rust programming language is fundamentally about empowerment: no matter what kind of code you are writing now, Rust empowers you to reach farther, to program with confidence in a wider variety of domains than you did before.
@@ -71,7 +79,7 @@ fn slice_all() {
     });
 
     let cjh = thread::spawn(move || {
-        let mut bytes = vec![0u8; smsg.as_bytes().len()];
+        let mut bytes = vec![0u8; smsg.len()];
         assert_eq!(cons.pop_slice_all(&mut bytes, TIMEOUT), bytes.len());
         assert_eq!(cons.pop_wait(TIMEOUT).unwrap(), 0);
         String::from_utf8(bytes).unwrap()
@@ -106,3 +114,50 @@ fn iter_all() {
 
     assert_eq!(smsg, rmsg);
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn drain_until() {
+    let rb = BlockingHeapRb::<u8>::new(7);
+    let (mut prod, mut cons) = rb.split();
+
+    let pjh = thread::spawn(move || {
+        for i in 0..3 {
+            prod.push(i, TIMEOUT).unwrap();
+            thread::sleep(Duration::from_millis(10));
+        }
+        thread::sleep(Duration::from_millis(400));
+        prod.push(3, TIMEOUT).unwrap();
+    });
+
+    assert_eq!(cons.drain_until(Instant::now() + Duration::from_millis(1000), 2), [0, 1]);
+
+    let deadline = Instant::now() + Duration::from_millis(200);
+    assert_eq!(cons.drain_until(deadline, 4), [2]);
+    assert!(Instant::now() >= deadline);
+
+    assert_eq!(cons.drain_until(Instant::now() + Duration::from_millis(1000), 1), [3]);
+    pjh.join().unwrap();
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn spin() {
+    let (prod, cons) = HeapRb::<i32>::new(1).split();
+    let (mut prod, mut cons) = (SpinProd::new(prod), SpinCons::new(cons));
+
+    assert_eq!(cons.pop_spin_limit(100), None);
+    assert_eq!(prod.push_spin_limit(1, 100), Ok(()));
+    assert_eq!(prod.push_spin_limit(2, 100), Err(2));
+
+    let pjh = thread::spawn(move || {
+        for i in 2..100 {
+            prod.push_spin(i);
+        }
+    });
+    for i in 1..100 {
+        assert_eq!(cons.pop_spin(), i);
+    }
+    pjh.join().unwrap();
+    assert_eq!(cons.pop_spin_limit(100), None);
+}
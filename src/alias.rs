@@ -5,6 +5,8 @@ use super::{
     rb::SharedRb,
     storage::Static,
 };
+#[cfg(feature = "allocator_api")]
+use alloc::alloc::Global;
 #[cfg(feature = "alloc")]
 use alloc::sync::Arc;
 
@@ -20,8 +22,11 @@ pub type StaticProd<'a, T, const N: usize> = Prod<&'a StaticRb<T, N>>;
 pub type StaticCons<'a, T, const N: usize> = Cons<&'a StaticRb<T, N>>;
 
 /// Heap-allocated ring buffer.
-#[cfg(feature = "alloc")]
+#[cfg(all(feature = "alloc", not(feature = "allocator_api")))]
 pub type HeapRb<T> = SharedRb<Heap<T>>;
+/// Heap-allocated ring buffer.
+#[cfg(feature = "allocator_api")]
+pub type HeapRb<T, A = Global> = SharedRb<Heap<T, A>>;
 
 #[cfg(feature = "alloc")]
 /// Alias for [`HeapRb`] producer.
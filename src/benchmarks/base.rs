@@ -40,3 +40,81 @@ fn push_pop_x100(b: &mut Bencher) {
         }
     });
 }
+
+#[bench]
+fn push_x100_local(b: &mut Bencher) {
+    let mut buf = LocalRb::<Static<u64, RB_SIZE>>::default();
+    buf.push_slice(&[1; RB_SIZE / 2]);
+    b.iter(|| {
+        for _ in 0..BATCH_SIZE {
+            buf.try_push(1).unwrap();
+        }
+        buf.skip(BATCH_SIZE);
+    });
+}
+
+#[bench]
+fn pop_x100_local(b: &mut Bencher) {
+    let mut buf = LocalRb::<Static<u64, RB_SIZE>>::default();
+    buf.push_slice(&[1; RB_SIZE / 2]);
+    b.iter(|| {
+        for _ in 0..BATCH_SIZE {
+            black_box(buf.try_pop().unwrap());
+        }
+        buf.push_slice(&[1; BATCH_SIZE]);
+    });
+}
+
+/// Producer and consumer in different threads contend for the indices.
+///
+/// Compare results with and without `compact` feature to see the cost of false sharing.
+#[bench]
+fn push_pop_threads(b: &mut Bencher) {
+    use std::{
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
+        thread,
+    };
+
+    let buf = SharedRb::<Static<u64, RB_SIZE>>::default();
+    let (mut prod, mut cons) = buf.split();
+    let done = Arc::new(AtomicBool::new(false));
+    let handle = thread::spawn({
+        let done = done.clone();
+        move || {
+            while !done.load(Ordering::Relaxed) {
+                if black_box(cons.try_pop()).is_none() {
+                    thread::yield_now();
+                }
+            }
+        }
+    });
+    b.iter(|| {
+        let mut count = 0;
+        while count < BATCH_SIZE {
+            if prod.try_push(1).is_ok() {
+                count += 1;
+            } else {
+                thread::yield_now();
+            }
+        }
+    });
+    done.store(true, Ordering::Relaxed);
+    handle.join().unwrap();
+}
+
+#[bench]
+fn len_shared(b: &mut Bencher) {
+    let mut buf = SharedRb::<Static<u64, RB_SIZE>>::default();
+    buf.push_slice(&[1; RB_SIZE / 2]);
+    b.iter(|| black_box(black_box(&buf).occupied_len()));
+}
+
+#[bench]
+fn len_relaxed_shared(b: &mut Bencher) {
+    let mut buf = SharedRb::<Static<u64, RB_SIZE>>::default();
+    buf.push_slice(&[1; RB_SIZE / 2]);
+    b.iter(|| black_box(black_box(&buf).len_relaxed()));
+}
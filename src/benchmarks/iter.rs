@@ -34,3 +34,18 @@ fn pop_iter_x1000(b: &mut Bencher) {
         unsafe { prod.advance_write_index(1000) };
     });
 }
+
+#[bench]
+fn push_iter_chain_x1000(b: &mut Bencher) {
+    let buf = HeapRb::<i32>::new(RB_SIZE);
+    let (mut prod, mut cons) = buf.split();
+
+    prod.push_slice(&[0; RB_SIZE / 2]);
+    cons.skip(RB_SIZE / 2);
+
+    b.iter(|| {
+        prod.push_iter((0..500).chain(500..1000));
+        black_box(cons.as_slices());
+        unsafe { cons.advance_read_index(1000) };
+    });
+}
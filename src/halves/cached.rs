@@ -1,6 +1,7 @@
 use super::{
     direct::Obs,
     frozen::{FrozenCons, FrozenProd},
+    send::{self, SendError},
 };
 use crate::{
     impl_consumer_traits, impl_producer_traits,
@@ -10,11 +11,17 @@ use crate::{
 use core::{mem::MaybeUninit, num::NonZeroUsize};
 
 /// Caching producer of ring buffer.
+///
+/// The index cache is stored by value and there are no self-references, so the producer can be freely moved.
+/// It is `Send` as long as `R` is, but it's `!Sync` because the cache is stored in [`Cell`](`core::cell::Cell`)s.
 pub struct CachingProd<R: RbRef> {
     frozen: FrozenProd<R>,
 }
 
 /// Caching consumer of ring buffer.
+///
+/// The index cache is stored by value and there are no self-references, so the consumer can be freely moved.
+/// It is `Send` as long as `R` is, but it's `!Sync` because the cache is stored in [`Cell`](`core::cell::Cell`)s.
 pub struct CachingCons<R: RbRef> {
     frozen: FrozenCons<R>,
 }
@@ -29,6 +36,15 @@ impl<R: RbRef> CachingProd<R> {
         }
     }
 }
+impl<R: RbRef> CachingProd<R> {
+    /// Appends an item to the ring buffer.
+    ///
+    /// Unlike [`Producer::try_push`] the error tells whether the ring buffer is full or the consumer is dropped.
+    /// If the consumer is dropped then the item is not appended even if there is vacant space.
+    pub fn send(&mut self, item: <Self as Observer>::Item) -> Result<(), SendError<<Self as Observer>::Item>> {
+        send::send(self, item)
+    }
+}
 impl<R: RbRef> ToRbRef for CachingProd<R> {
     type RbRef = R;
 
@@ -78,6 +94,11 @@ impl<R: RbRef> Observer for CachingProd<R> {
         self.frozen.write_index()
     }
 
+    #[inline]
+    fn label(&self) -> Option<&'static str> {
+        self.frozen.label()
+    }
+
     unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&mut [MaybeUninit<Self::Item>], &mut [MaybeUninit<Self::Item>]) {
         self.frozen.unsafe_slices(start, end)
     }
@@ -101,6 +122,11 @@ impl<R: RbRef> Observer for CachingCons<R> {
         self.frozen.write_index()
     }
 
+    #[inline]
+    fn label(&self) -> Option<&'static str> {
+        self.frozen.label()
+    }
+
     unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&mut [MaybeUninit<Self::Item>], &mut [MaybeUninit<Self::Item>]) {
         self.frozen.unsafe_slices(start, end)
     }
@@ -113,6 +139,16 @@ impl<R: RbRef> Producer for CachingProd<R> {
         self.frozen.commit();
     }
 
+    #[inline]
+    fn publish(&self) {
+        self.frozen.sync();
+    }
+
+    #[inline]
+    fn is_disconnected(&self) -> bool {
+        self.frozen.is_disconnected()
+    }
+
     fn try_push(&mut self, elem: Self::Item) -> Result<(), Self::Item> {
         if self.frozen.is_full() {
             self.frozen.fetch();
@@ -1,8 +1,10 @@
+use super::send::{self, SendError};
 use crate::{
     delegate_observer, impl_consumer_traits, impl_producer_traits,
     rb::traits::{RbRef, ToRbRef},
-    traits::{Consumer, Observe, Observer, Producer},
+    traits::{Consumer, Observe, Observer, Producer, RingBuffer},
 };
+use core::{cell::Cell, marker::PhantomData, mem::ManuallyDrop, ptr};
 
 /// Observer of ring buffer.
 #[derive(Clone)]
@@ -10,14 +12,26 @@ pub struct Obs<R: RbRef> {
     rb: R,
 }
 /// Producer of ring buffer.
+///
+/// It is `Send` as long as `R` is, but never `Sync`.
 pub struct Prod<R: RbRef> {
     rb: R,
+    _unsync: PhantomUnsync,
 }
 /// Consumer of ring buffer.
+///
+/// It is `Send` as long as `R` is, but never `Sync`.
 pub struct Cons<R: RbRef> {
     rb: R,
+    _unsync: PhantomUnsync,
 }
 
+/// Makes a half `!Sync` while keeping it `Send` as long as its ring buffer reference is.
+///
+/// A half shared between threads would allow them to access the same items simultaneously (e.g. via [`Consumer::as_slices`]),
+/// but items are only required to be `Send`.
+type PhantomUnsync = PhantomData<Cell<()>>;
+
 impl<R: RbRef> Obs<R> {
     pub fn new(rb: R) -> Self {
         Self { rb }
@@ -28,7 +42,8 @@ impl<R: RbRef> Prod<R> {
     ///
     /// There must be no more than one consumer wrapper.
     pub unsafe fn new(rb: R) -> Self {
-        Self { rb }
+        rb.deref().hold_write(true);
+        Self { rb, _unsync: PhantomData }
     }
 }
 impl<R: RbRef> Cons<R> {
@@ -36,7 +51,8 @@ impl<R: RbRef> Cons<R> {
     ///
     /// There must be no more than one consumer wrapper.
     pub unsafe fn new(rb: R) -> Self {
-        Self { rb }
+        rb.deref().hold_read(true);
+        Self { rb, _unsync: PhantomData }
     }
 }
 impl<R: RbRef> ToRbRef for Obs<R> {
@@ -48,13 +64,24 @@ impl<R: RbRef> ToRbRef for Obs<R> {
         self.rb
     }
 }
+impl<R: RbRef> Prod<R> {
+    /// Appends an item to the ring buffer.
+    ///
+    /// Unlike [`Producer::try_push`] the error tells whether the ring buffer is full or the consumer is dropped.
+    /// If the consumer is dropped then the item is not appended even if there is vacant space.
+    pub fn send(&mut self, item: <Self as Observer>::Item) -> Result<(), SendError<<Self as Observer>::Item>> {
+        send::send(self, item)
+    }
+}
 impl<R: RbRef> ToRbRef for Prod<R> {
     type RbRef = R;
     fn rb_ref(&self) -> &R {
         &self.rb
     }
     fn into_rb_ref(self) -> R {
-        self.rb
+        let this = ManuallyDrop::new(self);
+        unsafe { this.rb().hold_write(false) };
+        unsafe { ptr::read(&this.rb) }
     }
 }
 impl<R: RbRef> ToRbRef for Cons<R> {
@@ -63,7 +90,20 @@ impl<R: RbRef> ToRbRef for Cons<R> {
         &self.rb
     }
     fn into_rb_ref(self) -> R {
-        self.rb
+        let this = ManuallyDrop::new(self);
+        unsafe { this.rb().hold_read(false) };
+        unsafe { ptr::read(&this.rb) }
+    }
+}
+
+impl<R: RbRef> Drop for Prod<R> {
+    fn drop(&mut self) {
+        unsafe { self.rb().hold_write(false) };
+    }
+}
+impl<R: RbRef> Drop for Cons<R> {
+    fn drop(&mut self) {
+        unsafe { self.rb().hold_read(false) };
     }
 }
 
@@ -84,6 +124,11 @@ impl<R: RbRef> Producer for Prod<R> {
     unsafe fn set_write_index(&self, value: usize) {
         self.rb().set_write_index(value)
     }
+
+    #[inline]
+    fn is_disconnected(&self) -> bool {
+        self.rb().read_is_released()
+    }
 }
 
 impl<R: RbRef> Consumer for Cons<R> {
@@ -2,8 +2,9 @@
 
 use super::direct::{Cons, Obs, Prod};
 use crate::{
+    impl_producer_traits,
     rb::traits::{RbRef, ToRbRef},
-    traits::{Consumer, Observe, Observer, Producer},
+    traits::{Consumer, Observe, Observer, Producer, RingBuffer},
 };
 use core::{
     cell::Cell,
@@ -49,6 +50,11 @@ impl<R: RbRef> Observer for FrozenCons<R> {
         self.write.get()
     }
 
+    #[inline]
+    fn label(&self) -> Option<&'static str> {
+        self.rb().label()
+    }
+
     unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&mut [MaybeUninit<Self::Item>], &mut [MaybeUninit<Self::Item>]) {
         self.rb().unsafe_slices(start, end)
     }
@@ -71,6 +77,11 @@ impl<R: RbRef> Observer for FrozenProd<R> {
         self.write.get()
     }
 
+    #[inline]
+    fn label(&self) -> Option<&'static str> {
+        self.rb().label()
+    }
+
     unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&mut [MaybeUninit<Self::Item>], &mut [MaybeUninit<Self::Item>]) {
         self.rb().unsafe_slices(start, end)
     }
@@ -88,20 +99,34 @@ impl<R: RbRef> Producer for FrozenProd<R> {
     unsafe fn set_write_index(&self, value: usize) {
         self.write.set(value);
     }
+
+    #[inline]
+    fn publish(&self) {
+        self.commit();
+    }
+
+    #[inline]
+    fn is_disconnected(&self) -> bool {
+        self.rb().read_is_released()
+    }
 }
 
 impl<R: RbRef> Drop for FrozenCons<R> {
     fn drop(&mut self) {
         self.commit();
+        unsafe { self.rb().hold_read(false) };
     }
 }
 
 impl<R: RbRef> Drop for FrozenProd<R> {
     fn drop(&mut self) {
         self.commit();
+        unsafe { self.rb().hold_write(false) };
     }
 }
 
+impl_producer_traits!(FrozenProd<R: RbRef>);
+
 impl<R: RbRef> FrozenCons<R> {
     /// Create new ring buffer cache.
     ///
@@ -109,12 +134,25 @@ impl<R: RbRef> FrozenCons<R> {
     ///
     /// There must be only one instance containing the same ring buffer reference.
     pub unsafe fn new(rb: R) -> Self {
+        rb.deref().hold_read(true);
         Self {
             read: Cell::new(rb.deref().read_index()),
             write: Cell::new(rb.deref().write_index()),
             rb,
         }
     }
+    /// The `read` index known to `Self`, including changes that aren't committed yet.
+    ///
+    /// *Intended for diagnostics only.*
+    pub fn cached_index(&self) -> usize {
+        self.read.get()
+    }
+    /// The `read` index stored in the ring buffer, i.e. visible to the opposite end.
+    ///
+    /// *Intended for diagnostics only.*
+    pub fn shared_index(&self) -> usize {
+        self.rb().read_index()
+    }
     /// Commit and destroy `Self` returning underlying consumer.
     pub fn release(self) -> Cons<R> {
         unsafe { Cons::new(self.into_rb_ref()) }
@@ -143,6 +181,7 @@ impl<R: RbRef> ToRbRef for FrozenCons<R> {
     fn into_rb_ref(self) -> R {
         self.commit();
         let this = ManuallyDrop::new(self);
+        unsafe { this.rb().hold_read(false) };
         unsafe { ptr::read(&this.rb) }
     }
 }
@@ -154,12 +193,25 @@ impl<R: RbRef> FrozenProd<R> {
     ///
     /// There must be only one instance containing the same ring buffer reference.
     pub unsafe fn new(rb: R) -> Self {
+        rb.deref().hold_write(true);
         Self {
             read: Cell::new(rb.deref().read_index()),
             write: Cell::new(rb.deref().write_index()),
             rb,
         }
     }
+    /// The `write` index known to `Self`, including changes that aren't committed yet.
+    ///
+    /// *Intended for diagnostics only.*
+    pub fn cached_index(&self) -> usize {
+        self.write.get()
+    }
+    /// The `write` index stored in the ring buffer, i.e. visible to the opposite end.
+    ///
+    /// *Intended for diagnostics only.*
+    pub fn shared_index(&self) -> usize {
+        self.rb().write_index()
+    }
     /// Commit and destroy `Self` returning underlying producer.
     pub fn release(self) -> Prod<R> {
         unsafe { Prod::new(self.into_rb_ref()) }
@@ -198,6 +250,7 @@ impl<R: RbRef> ToRbRef for FrozenProd<R> {
     fn into_rb_ref(self) -> R {
         self.commit();
         let this = ManuallyDrop::new(self);
+        unsafe { this.rb().hold_write(false) };
         unsafe { ptr::read(&this.rb) }
     }
 }
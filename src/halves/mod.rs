@@ -1,8 +1,14 @@
 mod cached;
 mod direct;
 mod frozen;
+#[cfg(feature = "alloc")]
+mod observer;
+mod send;
 
 pub use cached::{CachingCons, CachingProd};
 pub use direct::{Cons, Obs, Prod};
 #[allow(unused_imports)]
 pub(crate) use frozen::{FrozenCons, FrozenProd};
+#[cfg(feature = "alloc")]
+pub use observer::ObserverHandle;
+pub use send::{SendError, SendErrorKind};
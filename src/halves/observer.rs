@@ -0,0 +1,45 @@
+use super::CachingCons;
+use crate::{
+    delegate_observer,
+    rb::{traits::ToRbRef, SharedRb},
+    storage::Storage,
+    traits::Observer,
+};
+use alloc::sync::Arc;
+
+/// Read-only handle to a ring buffer shared with its consumer.
+///
+/// The handle can be cloned and sent to other threads to monitor the ring buffer (see [`Observer`] methods),
+/// e.g. its [`occupied_len`](`Observer::occupied_len`).
+///
+/// It doesn't provide access to items: only the consumer may read them, since vacant places are written by the producer concurrently.
+/// To inspect the contents use [`Consumer::freeze`](`crate::traits::Consumer::freeze`) on the consumer side.
+pub struct ObserverHandle<S: Storage> {
+    rb: Arc<SharedRb<S>>,
+}
+
+impl<S: Storage> Clone for ObserverHandle<S> {
+    fn clone(&self) -> Self {
+        Self { rb: self.rb.clone() }
+    }
+}
+
+impl<S: Storage> ObserverHandle<S> {
+    fn rb(&self) -> &SharedRb<S> {
+        &self.rb
+    }
+}
+
+impl<S: Storage> Observer for ObserverHandle<S> {
+    delegate_observer!(SharedRb<S>, Self::rb);
+}
+
+impl<S: Storage> CachingCons<Arc<SharedRb<S>>> {
+    /// Creates a read-only handle to the ring buffer.
+    ///
+    /// The consumer cannot be cloned itself because two consumers removing items would corrupt the ring buffer,
+    /// so the handle can only count items.
+    pub fn observer_handle(&self) -> ObserverHandle<S> {
+        ObserverHandle { rb: self.rb_ref().clone() }
+    }
+}
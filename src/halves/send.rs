@@ -0,0 +1,51 @@
+use crate::traits::Producer;
+use core::fmt;
+
+/// Reason why an item cannot be sent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SendErrorKind {
+    /// The ring buffer is full.
+    Full,
+    /// The consumer has been dropped, so the item will never be received.
+    Disconnected,
+}
+
+/// An error returned from `send` methods of producers.
+///
+/// Contains the item that hasn't been sent.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SendError<T> {
+    pub item: T,
+    pub reason: SendErrorKind,
+}
+
+impl<T> fmt::Debug for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SendError").field("reason", &self.reason).finish_non_exhaustive()
+    }
+}
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.reason {
+            SendErrorKind::Full => f.write_str("sending into a full ring buffer"),
+            SendErrorKind::Disconnected => f.write_str("sending into a ring buffer without consumer"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for SendError<T> {}
+
+pub(crate) fn send<P: Producer>(this: &mut P, item: P::Item) -> Result<(), SendError<P::Item>> {
+    if this.is_disconnected() {
+        return Err(SendError {
+            item,
+            reason: SendErrorKind::Disconnected,
+        });
+    }
+    this.try_push(item).map_err(|item| SendError {
+        item,
+        reason: SendErrorKind::Full,
+    })
+}
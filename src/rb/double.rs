@@ -0,0 +1,125 @@
+use super::SharedRb;
+use crate::storage::Heap;
+use alloc::sync::Arc;
+use core::{
+    cell::UnsafeCell,
+    hint::spin_loop,
+    ops::Deref,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+/// Pair of ring buffers where readers always see the front one while the writer fills the back one.
+///
+/// The writer swaps the buffers using [`DoubleBufferWriter::publish`],
+/// so readers observe only complete contents of previously published buffer and never see it being modified.
+///
+/// # Single writer
+///
+/// There may be only one writer at a time, see [`Self::writer`].
+/// Any number of readers is allowed.
+pub struct DoubleBuffer<T> {
+    buffers: [UnsafeCell<SharedRb<Heap<T>>>; 2],
+    front: AtomicUsize,
+    readers: [AtomicUsize; 2],
+    writer: AtomicBool,
+}
+
+unsafe impl<T: Send> Send for DoubleBuffer<T> {}
+unsafe impl<T: Send + Sync> Sync for DoubleBuffer<T> {}
+
+impl<T> DoubleBuffer<T> {
+    /// Creates a pair of empty ring buffers with specified capacity.
+    ///
+    /// *Panics if allocation failed or `capacity` is zero.*
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            buffers: [UnsafeCell::new(SharedRb::new(capacity)), UnsafeCell::new(SharedRb::new(capacity))],
+            front: AtomicUsize::new(0),
+            readers: [AtomicUsize::new(0), AtomicUsize::new(0)],
+            writer: AtomicBool::new(false),
+        }
+    }
+
+    /// Provides access to the front buffer.
+    ///
+    /// While the returned guard is alive the buffer cannot be modified,
+    /// so it should be dropped as soon as possible because [`DoubleBufferWriter::publish`] waits for it.
+    pub fn front(&self) -> FrontGuard<'_, T> {
+        loop {
+            let index = self.front.load(Ordering::SeqCst);
+            self.readers[index].fetch_add(1, Ordering::SeqCst);
+            // Buffer could be swapped before we've registered as a reader.
+            if self.front.load(Ordering::SeqCst) == index {
+                break FrontGuard { owner: self, index };
+            }
+            self.readers[index].fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    /// Takes the writer.
+    ///
+    /// Returns `None` if the writer is already taken and not dropped yet.
+    pub fn writer(self: &Arc<Self>) -> Option<DoubleBufferWriter<T>> {
+        if self.writer.swap(true, Ordering::Acquire) {
+            None
+        } else {
+            Some(DoubleBufferWriter { owner: self.clone() })
+        }
+    }
+}
+
+/// Shared access to the front buffer of [`DoubleBuffer`].
+pub struct FrontGuard<'a, T> {
+    owner: &'a DoubleBuffer<T>,
+    index: usize,
+}
+
+impl<'a, T> Deref for FrontGuard<'a, T> {
+    type Target = SharedRb<Heap<T>>;
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.owner.buffers[self.index].get() }
+    }
+}
+
+impl<'a, T> Drop for FrontGuard<'a, T> {
+    fn drop(&mut self) {
+        self.owner.readers[self.index].fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// The only writer of [`DoubleBuffer`].
+pub struct DoubleBufferWriter<T> {
+    owner: Arc<DoubleBuffer<T>>,
+}
+
+impl<T> DoubleBufferWriter<T> {
+    /// Returns the buffer being owned by this writer.
+    pub fn owner(&self) -> &Arc<DoubleBuffer<T>> {
+        &self.owner
+    }
+
+    /// Provides exclusive access to the back buffer.
+    pub fn back(&mut self) -> &mut SharedRb<Heap<T>> {
+        let index = 1 - self.owner.front.load(Ordering::SeqCst);
+        unsafe { &mut *self.owner.buffers[index].get() }
+    }
+
+    /// Swaps the front and back buffers.
+    ///
+    /// After that the back buffer contains the contents published previously.
+    ///
+    /// Waits until all readers of the previous front buffer release it.
+    pub fn publish(&mut self) {
+        let back = self.owner.front.load(Ordering::SeqCst);
+        self.owner.front.store(1 - back, Ordering::SeqCst);
+        while self.owner.readers[back].load(Ordering::SeqCst) != 0 {
+            spin_loop();
+        }
+    }
+}
+
+impl<T> Drop for DoubleBufferWriter<T> {
+    fn drop(&mut self) {
+        self.owner.writer.store(false, Ordering::Release);
+    }
+}
@@ -0,0 +1,51 @@
+use alloc::sync::Arc;
+use core::{fmt, ops::Deref};
+
+/// Immutable contiguous copy of ring buffer contents.
+///
+/// Created by [`Consumer::freeze`](`crate::traits::Consumer::freeze`).
+/// Items are stored in a reference-counted slice, so the copy is cheap to clone and can be shared between threads for read-only access.
+/// It doesn't depend on the ring buffer it was created from.
+pub struct FrozenRb<T> {
+    items: Arc<[T]>,
+}
+
+impl<T> FrozenRb<T> {
+    /// Items from the oldest to the newest.
+    pub fn as_slice(&self) -> &[T] {
+        &self.items
+    }
+}
+
+impl<T> Clone for FrozenRb<T> {
+    fn clone(&self) -> Self {
+        Self { items: self.items.clone() }
+    }
+}
+
+impl<T> Deref for FrozenRb<T> {
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        &self.items
+    }
+}
+
+impl<T> AsRef<[T]> for FrozenRb<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.items
+    }
+}
+
+impl<T> FromIterator<T> for FrozenRb<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            items: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for FrozenRb<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.items.iter()).finish()
+    }
+}
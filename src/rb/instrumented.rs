@@ -0,0 +1,176 @@
+#[cfg(feature = "alloc")]
+use crate::traits::Split;
+use crate::{
+    delegate_observer,
+    halves::{CachingCons, CachingProd},
+    impl_consumer_traits, impl_producer_traits,
+    rb::SharedRb,
+    storage::Storage,
+    traits::{Consumer, Observer, Producer, RingBuffer, SplitRef},
+};
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+#[cfg(feature = "std")]
+use std::{sync::Mutex, vec::Vec};
+
+/// Index movement recorded by [`InstrumentedRb`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Event {
+    /// Read index was advanced by specified number of items.
+    Read(usize),
+    /// Write index was advanced by specified number of items.
+    Write(usize),
+}
+
+/// Receiver of [`Event`]s.
+///
+/// # Reentrancy
+///
+/// Tracer is called after the index has been stored, so it may freely inspect the ring buffer (e.g. call [`Observer`] methods).
+/// But it must not move the index of the same side it was called from (i.e. pop items from [`Event::Read`] or push items from [`Event::Write`]),
+/// otherwise it will be called recursively.
+pub trait Tracer {
+    /// Receives an index movement right after it has been stored.
+    fn trace(&self, event: Event);
+}
+
+/// Tracer that does nothing.
+impl Tracer for () {
+    #[inline]
+    fn trace(&self, _: Event) {}
+}
+
+impl<F: Fn(Event)> Tracer for F {
+    #[inline]
+    fn trace(&self, event: Event) {
+        self(event)
+    }
+}
+
+/// Tracer that stores last `N` events.
+#[cfg(feature = "std")]
+pub struct EventLog<const N: usize> {
+    events: Mutex<crate::LocalRb<crate::storage::Static<Event, N>>>,
+}
+
+#[cfg(feature = "std")]
+impl<const N: usize> Default for EventLog<N> {
+    fn default() -> Self {
+        Self {
+            events: Mutex::new(crate::LocalRb::default()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const N: usize> EventLog<N> {
+    /// Returns recorded events from the oldest to the newest.
+    pub fn events(&self) -> Vec<Event> {
+        self.events.lock().unwrap().iter().copied().collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const N: usize> Tracer for EventLog<N> {
+    fn trace(&self, event: Event) {
+        self.events.lock().unwrap().push_overwrite(event);
+    }
+}
+
+/// Ring buffer that reports every read and write index movement to a [`Tracer`].
+///
+/// Movements by zero items are not reported.
+///
+/// When the tracer is a no-op (e.g. `()`) the only overhead is one extra index load per operation.
+pub struct InstrumentedRb<S: Storage, T: Tracer> {
+    base: SharedRb<S>,
+    tracer: T,
+}
+
+impl<S: Storage, T: Tracer> InstrumentedRb<S, T> {
+    /// Wraps the ring buffer reporting its index movements to `tracer`.
+    pub fn new(base: SharedRb<S>, tracer: T) -> Self {
+        Self { base, tracer }
+    }
+    /// Returns the tracer.
+    pub fn tracer(&self) -> &T {
+        &self.tracer
+    }
+    /// Destructures into underlying ring buffer and tracer.
+    pub fn into_parts(self) -> (SharedRb<S>, T) {
+        (self.base, self.tracer)
+    }
+    fn base(&self) -> &SharedRb<S> {
+        &self.base
+    }
+}
+
+impl<S: Storage, T: Tracer> Observer for InstrumentedRb<S, T> {
+    delegate_observer!(SharedRb<S>, Self::base);
+}
+impl<S: Storage, T: Tracer> Producer for InstrumentedRb<S, T> {
+    unsafe fn set_write_index(&self, value: usize) {
+        let modulus = 2 * self.capacity().get();
+        let count = (modulus + value - self.base.write_index()) % modulus;
+        self.base.set_write_index(value);
+        if count != 0 {
+            self.tracer.trace(Event::Write(count));
+        }
+    }
+}
+impl<S: Storage, T: Tracer> Consumer for InstrumentedRb<S, T> {
+    unsafe fn set_read_index(&self, value: usize) {
+        let modulus = 2 * self.capacity().get();
+        let count = (modulus + value - self.base.read_index()) % modulus;
+        self.base.set_read_index(value);
+        if count != 0 {
+            self.tracer.trace(Event::Read(count));
+        }
+    }
+}
+// Delegating all methods to the base ring buffer would bypass the tracer (e.g. items removed by `push_overwrite` wouldn't be reported),
+// so only hold flags are forwarded and the rest use default implementations built on the traced index setters.
+impl<S: Storage, T: Tracer> RingBuffer for InstrumentedRb<S, T> {
+    #[inline]
+    fn read_is_held(&self) -> bool {
+        self.base.read_is_held()
+    }
+    #[inline]
+    fn write_is_held(&self) -> bool {
+        self.base.write_is_held()
+    }
+    #[inline]
+    fn read_is_released(&self) -> bool {
+        self.base.read_is_released()
+    }
+    #[inline]
+    unsafe fn hold_read(&self, flag: bool) -> bool {
+        self.base.hold_read(flag)
+    }
+    #[inline]
+    unsafe fn hold_write(&self, flag: bool) -> bool {
+        self.base.hold_write(flag)
+    }
+}
+
+impl<S: Storage, T: Tracer> SplitRef for InstrumentedRb<S, T> {
+    type RefProd<'a> = CachingProd<&'a Self> where Self: 'a;
+    type RefCons<'a> = CachingCons<&'a Self> where Self: 'a;
+
+    fn split_ref(&mut self) -> (Self::RefProd<'_>, Self::RefCons<'_>) {
+        unsafe { (CachingProd::new(self), CachingCons::new(self)) }
+    }
+}
+#[cfg(feature = "alloc")]
+impl<S: Storage, T: Tracer> Split for InstrumentedRb<S, T> {
+    type Prod = CachingProd<Arc<Self>>;
+    type Cons = CachingCons<Arc<Self>>;
+
+    fn split(self) -> (Self::Prod, Self::Cons) {
+        let arc = Arc::new(self);
+        unsafe { (CachingProd::new(arc.clone()), CachingCons::new(arc)) }
+    }
+}
+
+impl_producer_traits!(InstrumentedRb<S: Storage, T: Tracer>);
+impl_consumer_traits!(InstrumentedRb<S: Storage, T: Tracer>);
@@ -1,6 +1,8 @@
-use super::{macros::rb_impl_init, utils::ranges};
-#[cfg(feature = "alloc")]
-use crate::traits::Split;
+use super::{
+    macros::{rb_impl_init, rb_impl_policy_push},
+    utils::ranges,
+    Policy,
+};
 use crate::{
     halves::{Cons, Prod},
     impl_consumer_traits, impl_producer_traits,
@@ -8,6 +10,8 @@ use crate::{
     traits::{Consumer, Observer, Producer, RingBuffer, SplitRef},
 };
 #[cfg(feature = "alloc")]
+use crate::{storage::Heap, traits::Split};
+#[cfg(feature = "alloc")]
 use alloc::rc::Rc;
 use core::{
     cell::Cell,
@@ -17,10 +21,37 @@ use core::{
 };
 
 /// Ring buffer for single-threaded use only.
+///
+/// It is `!Sync`, so neither the ring buffer reference nor its producer and consumer can be sent to another thread.
+#[cfg_attr(
+    feature = "std",
+    doc = r##"
+```compile_fail
+use std::thread;
+use ringbuf::{LocalRb, storage::Heap, traits::*};
+
+let (prod, _cons) = LocalRb::<Heap<i32>>::new(2).split();
+thread::spawn(move || drop(prod));
+```
+
+```compile_fail
+use std::thread;
+use ringbuf::{LocalRb, storage::Heap, traits::*};
+
+let (_prod, cons) = LocalRb::<Heap<i32>>::new(2).split();
+thread::spawn(move || drop(cons));
+```
+"##
+)]
 pub struct LocalRb<S: Storage> {
     storage: Shared<S>,
     read: Cell<usize>,
     write: Cell<usize>,
+    read_held: Cell<bool>,
+    write_held: Cell<bool>,
+    read_released: Cell<bool>,
+    policy: Policy,
+    leak_on_drop: bool,
 }
 
 impl<S: Storage> LocalRb<S> {
@@ -35,6 +66,11 @@ impl<S: Storage> LocalRb<S> {
             storage: Shared::new(storage),
             read: Cell::new(read),
             write: Cell::new(write),
+            read_held: Cell::new(false),
+            write_held: Cell::new(false),
+            read_released: Cell::new(false),
+            policy: Policy::Reject,
+            leak_on_drop: false,
         }
     }
     /// Destructures ring buffer into underlying storage and `read` and `write` indices.
@@ -46,6 +82,117 @@ impl<S: Storage> LocalRb<S> {
         let this = ManuallyDrop::new(self);
         (ptr::read(&this.storage).into_inner(), this.read.get(), this.write.get())
     }
+    /// Creates an empty ring buffer from storage with specified overflow policy.
+    ///
+    /// The policy is applied to [`Producer`] methods called on the ring buffer itself.
+    /// Producers obtained by splitting the ring buffer always reject items when it is full
+    /// because they cannot remove items concurrently with the consumer.
+    ///
+    /// Note that with [`Policy::Overwrite`] bulk pushes consume all provided items, see its documentation for details.
+    pub fn with_policy(storage: S, policy: Policy) -> Self {
+        let mut this = unsafe { Self::from_raw_parts(storage, 0, 0) };
+        this.policy = policy;
+        this
+    }
+    /// Overflow policy of the ring buffer.
+    pub fn policy(&self) -> Policy {
+        self.policy
+    }
+    /// Sets whether items remaining in the ring buffer are leaked instead of being dropped when the ring buffer is dropped.
+    ///
+    /// **If enabled, destructors of the remaining items are never run, only the storage itself is freed.**
+    /// It is up to the caller to ensure that the items are owned (and eventually dropped) by someone else,
+    /// e.g. when the items are handles to memory of an arena that outlives the ring buffer. Otherwise the items are leaked.
+    ///
+    /// Items removed from the ring buffer by consumer are not affected by this flag and are dropped as usual.
+    pub fn set_leak_on_drop(&mut self, leak: bool) {
+        self.leak_on_drop = leak;
+    }
+    /// Whether items remaining in the ring buffer are leaked when the ring buffer is dropped.
+    pub fn leaks_on_drop(&self) -> bool {
+        self.leak_on_drop
+    }
+    /// Capacity of the ring buffer.
+    ///
+    /// Same as [`Observer::capacity`] but doesn't require the trait to be imported.
+    #[inline]
+    pub fn capacity(&self) -> NonZeroUsize {
+        Observer::capacity(self)
+    }
+    /// The number of items stored in the ring buffer.
+    ///
+    /// Same as [`Observer::occupied_len`] but doesn't require the trait to be imported.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.occupied_len()
+    }
+    /// Checks if the ring buffer is empty.
+    ///
+    /// Same as [`Observer::is_empty`] but doesn't require the trait to be imported.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        Observer::is_empty(self)
+    }
+    /// Appends an item to the ring buffer.
+    ///
+    /// Same as [`Producer::try_push`] but doesn't require the trait to be imported.
+    /// If the ring buffer is full then the item is handled according to the [policy](`Self::policy`).
+    #[inline]
+    pub fn push(&mut self, elem: S::Item) -> Result<(), S::Item> {
+        self.try_push(elem)
+    }
+    /// Removes the oldest item from the ring buffer.
+    ///
+    /// Same as [`Consumer::try_pop`] but doesn't require the trait to be imported.
+    #[inline]
+    pub fn pop(&mut self) -> Option<S::Item> {
+        self.try_pop()
+    }
+    /// Same as [`SplitRef::split_ref`] but returns `None` if producer or consumer of the ring buffer is still marked as held.
+    ///
+    /// Since this method takes `&mut self`, the borrow checker already guarantees that no halves are alive.
+    /// So it can return `None` only if a previously created half was leaked using [`core::mem::forget`] and never dropped.
+    pub fn try_split_ref(&mut self) -> Option<(Prod<&Self>, Cons<&Self>)> {
+        if self.read_is_held() || self.write_is_held() {
+            None
+        } else {
+            Some(self.split_ref())
+        }
+    }
+    /// Splits ring buffer into producer and consumer sharing the ring buffer via [`Rc`].
+    ///
+    /// Same as [`Split::split`] but doesn't require the trait to be imported.
+    /// There are no atomic operations, so the halves cannot be sent to another thread.
+    #[cfg(feature = "alloc")]
+    pub fn split_rc(self) -> (Prod<Rc<Self>>, Cons<Rc<Self>>) {
+        Split::split(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> LocalRb<Heap<T>> {
+    /// Appends an item to the ring buffer growing the storage if the buffer is full.
+    ///
+    /// When the buffer is full its capacity is doubled and existing items are moved to the beginning of the new storage.
+    /// So this method never fails regardless of the [`Policy`].
+    pub fn push_grow(&mut self, elem: T) {
+        if self.is_full() {
+            let capacity = 2 * self.capacity().get();
+            let mut storage = Heap::<T>::with_capacity(capacity);
+            storage.resize_with(capacity, MaybeUninit::uninit);
+            let (left, right) = self.occupied_slices();
+            let len = left.len() + right.len();
+            unsafe {
+                ptr::copy_nonoverlapping(left.as_ptr(), storage.as_mut_ptr(), left.len());
+                ptr::copy_nonoverlapping(right.as_ptr(), storage.as_mut_ptr().add(left.len()), right.len());
+            }
+            // Items have been moved, so the old storage is just deallocated.
+            self.storage = Shared::new(storage);
+            self.read.set(0);
+            self.write.set(len);
+        }
+        let _ = self.try_push(elem);
+    }
 }
 
 impl<S: Storage> Observer for LocalRb<S> {
@@ -76,6 +223,8 @@ impl<S: Storage> Producer for LocalRb<S> {
     unsafe fn set_write_index(&self, value: usize) {
         self.write.set(value);
     }
+
+    rb_impl_policy_push!();
 }
 
 impl<S: Storage> Consumer for LocalRb<S> {
@@ -85,11 +234,38 @@ impl<S: Storage> Consumer for LocalRb<S> {
     }
 }
 
-impl<S: Storage> RingBuffer for LocalRb<S> {}
+impl<S: Storage> RingBuffer for LocalRb<S> {
+    #[inline]
+    fn read_is_held(&self) -> bool {
+        self.read_held.get()
+    }
+    #[inline]
+    fn write_is_held(&self) -> bool {
+        self.write_held.get()
+    }
+    #[inline]
+    fn read_is_released(&self) -> bool {
+        self.read_released.get()
+    }
+    #[inline]
+    unsafe fn hold_read(&self, flag: bool) -> bool {
+        let prev = self.read_held.replace(flag);
+        if flag || prev {
+            self.read_released.set(!flag);
+        }
+        prev
+    }
+    #[inline]
+    unsafe fn hold_write(&self, flag: bool) -> bool {
+        self.write_held.replace(flag)
+    }
+}
 
 impl<S: Storage> Drop for LocalRb<S> {
     fn drop(&mut self) {
-        self.clear();
+        if !self.leak_on_drop {
+            self.clear();
+        }
     }
 }
 
@@ -1,11 +1,48 @@
 macro_rules! rb_impl_init {
     ($type:ident) => {
         impl<T, const N: usize> Default for $type<Static<T, N>> {
+            /// Creates an empty ring buffer with capacity of `N` items.
+            ///
+            /// Zero capacity is rejected at compile time:
+            ///
+            /// ```compile_fail
+            #[doc = concat!("use ringbuf::{", stringify!($type), ", storage::Static};")]
+            ///
+            #[doc = concat!("let _ = ", stringify!($type), "::<Static<i32, 0>>::default();")]
+            /// ```
             fn default() -> Self {
+                const { assert!(N > 0, "ring buffer capacity must be greater than zero") };
                 unsafe { Self::from_raw_parts(crate::utils::uninit_array(), usize::default(), usize::default()) }
             }
         }
 
+        #[cfg(any(test, feature = "unstable"))]
+        impl<S: crate::storage::Storage> $type<S> {
+            /// Sets `read` and `write` indices of the ring buffer at once.
+            ///
+            /// Intended for placing the ring buffer into a specific (e.g. wrapped) state in tests.
+            ///
+            /// *Panics if indices are not less than twice the capacity or the distance between them exceeds the capacity.*
+            ///
+            /// # Safety
+            ///
+            /// The items in storage inside `read..write` range must be initialized, items outside this range must be uninitialized.
+            /// Items that were initialized before the call and aren't in this range anymore are not dropped.
+            pub unsafe fn set_indices(&mut self, read: usize, write: usize) {
+                use crate::traits::{Consumer, Observer, Producer};
+
+                let capacity = Observer::capacity(self).get();
+                let modulus = 2 * capacity;
+                assert!(read < modulus && write < modulus, "index is out of range");
+                assert!(
+                    (modulus + write - read) % modulus <= capacity,
+                    "distance between indices exceeds capacity"
+                );
+                self.set_read_index(read);
+                self.set_write_index(write);
+            }
+        }
+
         #[cfg(feature = "alloc")]
         impl<T> $type<crate::storage::Heap<T>> {
             /// Creates a new instance of a ring buffer.
@@ -23,8 +60,143 @@ macro_rules! rb_impl_init {
                 data.resize_with(capacity, core::mem::MaybeUninit::uninit);
                 Ok(unsafe { Self::from_raw_parts(data, usize::default(), usize::default()) })
             }
+            /// Creates a new instance of a ring buffer with capacity of `capacity` items
+            /// and memory allocated for at least `capacity + spare` items.
+            ///
+            /// The ring buffer can be later grown up to the allocated size without reallocation using [`Self::grow_into_spare`].
+            ///
+            /// *Panics if allocation failed, `capacity` is zero or `capacity + spare` overflows.*
+            pub fn with_capacity_and_spare(capacity: usize, spare: usize) -> Self {
+                let total = capacity.checked_add(spare).expect("capacity with spare overflows usize");
+                let mut data = alloc::vec::Vec::with_capacity(total);
+                data.resize_with(capacity, core::mem::MaybeUninit::uninit);
+                unsafe { Self::from_raw_parts(data, usize::default(), usize::default()) }
+            }
+            /// The number of items that can be added to the capacity of the ring buffer without reallocation.
+            pub fn spare_capacity(&self) -> usize {
+                let data = self.storage.as_vec();
+                data.capacity() - data.len()
+            }
+            /// Increases capacity of the ring buffer up to `capacity` items using spare allocated memory.
+            ///
+            /// Items are moved within the storage (if they wrap around its end), but the storage is never reallocated.
+            /// Does nothing if `capacity` isn't greater than current one.
+            ///
+            /// If there isn't enough allocated memory then returns the maximal capacity available in `Err`.
+            pub fn grow_into_spare(&mut self, capacity: usize) -> Result<(), usize> {
+                use crate::traits::{Consumer, Observer, Producer, RingBuffer};
+
+                let max_capacity = self.storage.as_vec().capacity();
+                if capacity > max_capacity {
+                    return Err(max_capacity);
+                }
+                if capacity <= Observer::capacity(self).get() {
+                    return Ok(());
+                }
+                let len = self.occupied_len();
+                self.make_contiguous();
+                let start = self.read_index() % Observer::capacity(self);
+                // Storage is grown while there are no halves borrowing the ring buffer.
+                self.storage.grow(capacity);
+                unsafe {
+                    self.set_read_index(start);
+                    self.set_write_index(start + len);
+                }
+                Ok(())
+            }
+            /// Creates a new instance of a ring buffer with capacity fitting into `bytes` of memory.
+            ///
+            /// Capacity is `bytes / size_of::<T>()` but not less than one item.
+            ///
+            /// *Panics if allocation failed or `T` is zero-sized.*
+            pub fn with_byte_budget(bytes: usize) -> Self {
+                let size = core::mem::size_of::<T>();
+                assert!(size > 0, "byte budget is meaningless for zero-sized items");
+                Self::new(usize::max(bytes / size, 1))
+            }
+            /// Creates a new instance of a ring buffer containing items of `deque` in the same order.
+            ///
+            /// Capacity is equal to the length of `deque`. Empty `deque` produces a ring buffer with capacity of one item.
+            ///
+            /// *Panics if allocation failed.*
+            pub fn from_vec_deque(deque: alloc::collections::VecDeque<T>) -> Self {
+                let mut this = Self::new(usize::max(deque.len(), 1));
+                crate::traits::Producer::push_iter(&mut this, deque.into_iter());
+                this
+            }
+            /// Moves all items of the ring buffer into a new [`VecDeque`](`alloc::collections::VecDeque`) preserving their order.
+            pub fn into_vec_deque(mut self) -> alloc::collections::VecDeque<T> {
+                crate::traits::Consumer::pop_iter(&mut self).collect()
+            }
+        }
+
+        #[cfg(feature = "allocator_api")]
+        impl<T, A: core::alloc::Allocator> $type<crate::storage::Heap<T, A>> {
+            /// Creates a new instance of a ring buffer with storage allocated by `alloc`.
+            ///
+            /// *Panics if allocation failed or `capacity` is zero.*
+            pub fn new_in(capacity: usize, alloc: A) -> Self {
+                let mut data = alloc::vec::Vec::with_capacity_in(capacity, alloc);
+                data.resize_with(capacity, core::mem::MaybeUninit::uninit);
+                unsafe { Self::from_raw_parts(data, usize::default(), usize::default()) }
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl $type<crate::storage::Heap<u8>> {
+            /// Creates a new instance of a byte ring buffer with capacity of `bytes`.
+            ///
+            /// Same as [`Self::new`].
+            ///
+            /// *Panics if allocation failed or `bytes` is zero.*
+            pub fn with_byte_capacity(bytes: usize) -> Self {
+                Self::new(bytes)
+            }
+        }
+    };
+}
+
+/// Producer methods of ring buffer that take [`Policy`](`super::Policy`) into account.
+macro_rules! rb_impl_policy_push {
+    () => {
+        fn try_push(&mut self, elem: Self::Item) -> Result<(), Self::Item> {
+            if self.policy == crate::rb::Policy::Overwrite && self.is_full() {
+                self.skip(1);
+            }
+            super::utils::RawProd(&*self).try_push(elem)
+        }
+
+        fn push_iter<I: Iterator<Item = Self::Item>>(&mut self, iter: I) -> usize {
+            match self.policy {
+                crate::rb::Policy::Reject => super::utils::RawProd(&*self).push_iter(iter),
+                crate::rb::Policy::Overwrite => {
+                    let mut count = 0;
+                    for elem in iter {
+                        let _ = self.try_push(elem);
+                        count += 1;
+                    }
+                    count
+                }
+            }
+        }
+
+        fn push_slice(&mut self, elems: &[Self::Item]) -> usize
+        where
+            Self::Item: Copy,
+        {
+            if self.policy == crate::rb::Policy::Overwrite {
+                let tail = &elems[elems.len().saturating_sub(self.capacity().get())..];
+                if tail.len() > self.vacant_len() {
+                    self.skip(tail.len() - self.vacant_len());
+                }
+                super::utils::RawProd(&*self).push_slice(tail);
+                elems.len()
+            } else {
+                super::utils::RawProd(&*self).push_slice(elems)
+            }
         }
     };
 }
 
 pub(crate) use rb_impl_init;
+pub(crate) use rb_impl_policy_push;
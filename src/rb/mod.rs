@@ -1,8 +1,34 @@
+#[cfg(feature = "alloc")]
+pub mod double;
+#[cfg(feature = "alloc")]
+pub mod frozen;
+#[cfg(feature = "trace")]
+pub mod instrumented;
 pub mod local;
 mod macros;
+#[cfg(feature = "alloc")]
+pub mod mpsc;
+mod policy;
+#[cfg(feature = "std")]
+pub mod rate;
 pub mod shared;
+#[cfg(feature = "alloc")]
+pub mod stream;
 pub mod traits;
 mod utils;
 
+#[cfg(feature = "alloc")]
+pub use double::DoubleBuffer;
+#[cfg(feature = "alloc")]
+pub use frozen::FrozenRb;
+#[cfg(feature = "trace")]
+pub use instrumented::InstrumentedRb;
 pub use local::LocalRb;
+#[cfg(feature = "alloc")]
+pub use mpsc::MpscRb;
+pub use policy::Policy;
+#[cfg(feature = "std")]
+pub use rate::RateTrackedRb;
 pub use shared::SharedRb;
+#[cfg(feature = "alloc")]
+pub use stream::StreamRb;
@@ -0,0 +1,180 @@
+use crate::{
+    delegate_observer, impl_consumer_traits,
+    rb::{
+        utils::{atomic_index, AtomicIndex},
+        SharedRb,
+    },
+    storage::Heap,
+    traits::{Consumer, Observer, Producer},
+};
+use alloc::sync::Arc;
+use core::{hint::spin_loop, ptr, sync::atomic::Ordering};
+
+/// Underlying ring buffer of [`MpscRb`].
+type Base<T> = SharedRb<Heap<T>>;
+
+/// Ring buffer with multiple producers and a single consumer.
+///
+/// Producers reserve vacant places by moving an additional `reserved` index using compare-and-swap,
+/// so they write their items concurrently without locking.
+/// After that each producer waits until all previous reservations are committed and then moves the `write` index,
+/// so items become visible to the consumer in the order of reservation.
+///
+/// The consumer is lock-free and implements [`Consumer`] as usual.
+#[cfg_attr(
+    feature = "std",
+    doc = r##"
+```
+use std::thread;
+use ringbuf::{rb::mpsc::MpscRb, traits::*};
+
+let (prod, mut cons) = MpscRb::<i32>::new(16).split();
+let handles: Vec<_> = (0..2)
+    .map(|i| {
+        let prod = prod.clone();
+        thread::spawn(move || prod.try_push(i).unwrap())
+    })
+    .collect();
+handles.into_iter().for_each(|h| h.join().unwrap());
+
+let mut items = cons.pop_iter().collect::<Vec<_>>();
+items.sort();
+assert_eq!(items, [0, 1]);
+```
+"##
+)]
+pub struct MpscRb<T> {
+    base: Base<T>,
+    reserved: AtomicIndex,
+}
+
+/// Producer of [`MpscRb`].
+///
+/// It can be cloned to obtain more producers.
+pub struct MpscProd<T> {
+    rb: Arc<MpscRb<T>>,
+}
+
+/// Consumer of [`MpscRb`].
+pub struct MpscCons<T> {
+    rb: Arc<MpscRb<T>>,
+}
+
+impl<T> MpscRb<T> {
+    /// Creates a new ring buffer of specified capacity.
+    ///
+    /// *Panics if allocation failed or `capacity` is zero.*
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            base: SharedRb::new(capacity),
+            reserved: atomic_index(0),
+        }
+    }
+    /// Splits the ring buffer into producer and consumer.
+    pub fn split(self) -> (MpscProd<T>, MpscCons<T>) {
+        let rb = Arc::new(self);
+        (MpscProd { rb: rb.clone() }, MpscCons { rb })
+    }
+
+    /// Reserves at most `count` vacant places.
+    ///
+    /// Returns `start` and `end` indices of reserved range, or `None` if there are no vacant places.
+    ///
+    /// Vacant places are counted from `reserved` rather than `write` index,
+    /// so places reserved but not yet committed by other producers are considered occupied.
+    fn reserve(&self, count: usize) -> Option<(usize, usize)> {
+        let capacity = self.base.capacity().get();
+        let modulus = 2 * capacity;
+        let mut start = self.reserved.load(Ordering::Acquire);
+        loop {
+            let vacant = capacity - (modulus + start - self.base.read_index()) % modulus;
+            let count = usize::min(count, vacant);
+            if count == 0 {
+                return None;
+            }
+            let end = (start + count) % modulus;
+            match self.reserved.compare_exchange_weak(start, end, Ordering::AcqRel, Ordering::Acquire) {
+                Ok(_) => return Some((start, end)),
+                Err(actual) => start = actual,
+            }
+        }
+    }
+    /// Makes reserved range visible to the consumer.
+    ///
+    /// # Safety
+    ///
+    /// Range must be reserved by [`Self::reserve`] and all items in it must be initialized.
+    unsafe fn commit(&self, start: usize, end: usize) {
+        // Wait for previous reservations to be committed.
+        while self.base.write_index() != start {
+            spin_loop();
+        }
+        self.base.set_write_index(end);
+    }
+}
+
+impl<T> Clone for MpscProd<T> {
+    fn clone(&self) -> Self {
+        Self { rb: self.rb.clone() }
+    }
+}
+
+impl<T> MpscProd<T> {
+    /// Appends an item to the ring buffer.
+    ///
+    /// If buffer is full returns an `Err` containing the item that hasn't been appended.
+    pub fn try_push(&self, elem: T) -> Result<(), T> {
+        match self.rb.reserve(1) {
+            Some((start, end)) => unsafe {
+                self.rb.base.unsafe_slices(start, end).0[0].write(elem);
+                self.rb.commit(start, end);
+                Ok(())
+            },
+            None => Err(elem),
+        }
+    }
+    /// Appends items from slice to the ring buffer.
+    ///
+    /// Items are appended as a single contiguous sequence, items from other producers are placed before or after it.
+    ///
+    /// Returns count of items been appended to the ring buffer.
+    pub fn push_slice(&self, elems: &[T]) -> usize
+    where
+        T: Copy,
+    {
+        match self.rb.reserve(elems.len()) {
+            Some((start, end)) => unsafe {
+                let (left, right) = self.rb.base.unsafe_slices(start, end);
+                let (elems_left, elems_right) = elems.split_at(left.len());
+                ptr::copy_nonoverlapping(elems_left.as_ptr(), left.as_mut_ptr() as *mut T, left.len());
+                ptr::copy_nonoverlapping(elems_right.as_ptr(), right.as_mut_ptr() as *mut T, right.len());
+                self.rb.commit(start, end);
+                left.len() + right.len()
+            },
+            None => 0,
+        }
+    }
+    /// Capacity of the ring buffer.
+    pub fn capacity(&self) -> usize {
+        self.rb.base.capacity().get()
+    }
+}
+
+impl<T> MpscCons<T> {
+    fn base(&self) -> &Base<T> {
+        &self.rb.base
+    }
+}
+
+impl<T> Observer for MpscCons<T> {
+    delegate_observer!(Base<T>, Self::base);
+}
+
+impl<T> Consumer for MpscCons<T> {
+    #[inline]
+    unsafe fn set_read_index(&self, value: usize) {
+        self.base().set_read_index(value)
+    }
+}
+
+impl_consumer_traits!(MpscCons<T>);
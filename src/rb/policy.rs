@@ -0,0 +1,14 @@
+/// Behavior of the ring buffer when an item is pushed into it while it is full.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Policy {
+    /// Item is rejected and returned back.
+    #[default]
+    Reject,
+    /// The oldest item is removed from the ring buffer and dropped to make room for the new one.
+    ///
+    /// So pushing never stops on a full ring buffer: [`Producer::push_iter`](`crate::traits::Producer::push_iter`)
+    /// consumes the whole iterator (and never returns for an infinite one) and [`Producer::push_slice`](`crate::traits::Producer::push_slice`)
+    /// takes the whole slice. Both return count of items taken, including those displaced by the following items,
+    /// while only the last `capacity` of them are retained.
+    Overwrite,
+}
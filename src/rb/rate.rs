@@ -0,0 +1,211 @@
+use crate::{
+    delegate_observer,
+    halves::{CachingCons, CachingProd},
+    impl_consumer_traits, impl_producer_traits,
+    rb::{LocalRb, SharedRb},
+    storage::{Static, Storage},
+    traits::{Consumer, Observer, Producer, RingBuffer, Split, SplitRef},
+};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+/// Maximum number of stored samples.
+const SAMPLES: usize = 32;
+
+/// Timestamped total counts of items.
+type Samples = Mutex<LocalRb<Static<(Instant, usize), SAMPLES>>>;
+
+/// Ring buffer that estimates the rate at which items are pushed into it and detects stalled consumer.
+///
+/// The total counts of pushed and popped items are updated on every write and read,
+/// but the timestamp is recorded only once per `sample_period` items.
+/// Recording a sample takes a lock, but if the lock is contended (e.g. by [`Self::items_per_sec`]) the sample is skipped.
+pub struct RateTrackedRb<S: Storage> {
+    base: SharedRb<S>,
+    total: AtomicUsize,
+    samples: Samples,
+    total_read: AtomicUsize,
+    read_samples: Samples,
+    window: Duration,
+    sample_period: usize,
+}
+
+impl<S: Storage> RateTrackedRb<S> {
+    /// Wraps the ring buffer.
+    ///
+    /// Rate is estimated over the last `window` time.
+    /// Timestamp is recorded once per `sample_period` pushed items (zero is treated as one).
+    pub fn new(base: SharedRb<S>, window: Duration, sample_period: usize) -> Self {
+        let now = Instant::now();
+        let new_samples = || {
+            let mut samples = LocalRb::default();
+            samples.push_overwrite((now, 0));
+            Mutex::new(samples)
+        };
+        Self {
+            base,
+            total: AtomicUsize::new(0),
+            samples: new_samples(),
+            total_read: AtomicUsize::new(0),
+            read_samples: new_samples(),
+            window,
+            sample_period: usize::max(sample_period, 1),
+        }
+    }
+    /// Destructures into underlying ring buffer.
+    pub fn into_inner(self) -> SharedRb<S> {
+        self.base
+    }
+    /// Total number of items pushed into the ring buffer.
+    pub fn total_written(&self) -> usize {
+        self.total.load(Ordering::Acquire)
+    }
+    /// Total number of items popped from the ring buffer.
+    ///
+    /// Items discarded by overwriting pushes (e.g. [`RingBuffer::push_overwrite`]) are not counted.
+    pub fn total_read(&self) -> usize {
+        self.total_read.load(Ordering::Acquire)
+    }
+    /// Checks whether fewer than `min_progress` items have been popped since `since`.
+    ///
+    /// Intended for watchdogs to detect a wedged consumer.
+    ///
+    /// The count of items popped at `since` is taken from the newest sample recorded not later than `since`,
+    /// so the progress may be overestimated by up to `sample_period` items.
+    /// If there is no such sample (it was displaced by newer ones) then the oldest sample is used.
+    pub fn is_stalled(&self, since: Instant, min_progress: usize) -> bool {
+        let samples = self.read_samples.lock().unwrap();
+        let total = self.total_read();
+        let (_, count) = match samples.iter().rev().find(|(time, _)| *time <= since) {
+            Some(sample) => *sample,
+            None => *samples.iter().next().unwrap(),
+        };
+        total.wrapping_sub(count) < min_progress
+    }
+    /// Estimated number of items pushed per second over the last `window`.
+    ///
+    /// If there is no sample inside the window then the newest sample is used, so the estimate covers a longer time.
+    pub fn items_per_sec(&self) -> f64 {
+        let samples = self.samples.lock().unwrap();
+        let (now, total) = (Instant::now(), self.total_written());
+        let (time, count) = match samples.iter().find(|(time, _)| now.duration_since(*time) <= self.window) {
+            Some(sample) => *sample,
+            None => *samples.iter().last().unwrap(),
+        };
+        let elapsed = now.duration_since(time).as_secs_f64();
+        if elapsed > 0.0 {
+            total.wrapping_sub(count) as f64 / elapsed
+        } else {
+            0.0
+        }
+    }
+
+    fn base(&self) -> &SharedRb<S> {
+        &self.base
+    }
+    /// Adds `count` items to `total` and records a sample if it crosses a sample period boundary.
+    fn count(&self, total: &AtomicUsize, samples: &Samples, count: usize) {
+        if count != 0 {
+            let prev = total.fetch_add(count, Ordering::AcqRel);
+            let total = prev.wrapping_add(count);
+            if prev / self.sample_period != total / self.sample_period {
+                if let Ok(mut samples) = samples.try_lock() {
+                    samples.push_overwrite((Instant::now(), total));
+                }
+            }
+        }
+    }
+}
+
+impl<S: Storage> Observer for RateTrackedRb<S> {
+    delegate_observer!(SharedRb<S>, Self::base);
+}
+impl<S: Storage> Producer for RateTrackedRb<S> {
+    unsafe fn set_write_index(&self, value: usize) {
+        let modulus = 2 * self.capacity().get();
+        let count = (modulus + value - self.base.write_index()) % modulus;
+        self.base.set_write_index(value);
+        self.count(&self.total, &self.samples, count);
+    }
+}
+impl<S: Storage> Consumer for RateTrackedRb<S> {
+    unsafe fn set_read_index(&self, value: usize) {
+        let modulus = 2 * self.capacity().get();
+        let count = (modulus + value - self.base.read_index()) % modulus;
+        self.base.set_read_index(value);
+        self.count(&self.total_read, &self.read_samples, count);
+    }
+}
+// Hold flags are delegated and methods that move the `read` index without consuming items bypass the read counter,
+// other methods must use overridden index setters to be counted.
+impl<S: Storage> RingBuffer for RateTrackedRb<S> {
+    #[inline]
+    fn read_is_held(&self) -> bool {
+        self.base.read_is_held()
+    }
+    #[inline]
+    fn write_is_held(&self) -> bool {
+        self.base.write_is_held()
+    }
+    #[inline]
+    fn read_is_released(&self) -> bool {
+        self.base.read_is_released()
+    }
+    #[inline]
+    unsafe fn hold_read(&self, flag: bool) -> bool {
+        self.base.hold_read(flag)
+    }
+    #[inline]
+    unsafe fn hold_write(&self, flag: bool) -> bool {
+        self.base.hold_write(flag)
+    }
+
+    #[inline]
+    fn make_contiguous(&mut self) -> &mut [Self::Item] {
+        self.base.make_contiguous()
+    }
+    fn push_overwrite(&mut self, elem: Self::Item) -> Option<Self::Item> {
+        let ret = if self.is_full() { self.base.try_pop() } else { None };
+        let _ = self.try_push(elem);
+        ret
+    }
+    fn push_slice_overwrite(&mut self, elems: &[Self::Item])
+    where
+        Self::Item: Copy,
+    {
+        if elems.len() > self.vacant_len() {
+            self.base.skip(usize::min(elems.len() - self.vacant_len(), self.occupied_len()));
+        }
+        self.push_slice(if elems.len() > self.vacant_len() {
+            &elems[(elems.len() - self.vacant_len())..]
+        } else {
+            elems
+        });
+    }
+}
+
+impl<S: Storage> SplitRef for RateTrackedRb<S> {
+    type RefProd<'a> = CachingProd<&'a Self> where Self: 'a;
+    type RefCons<'a> = CachingCons<&'a Self> where Self: 'a;
+
+    fn split_ref(&mut self) -> (Self::RefProd<'_>, Self::RefCons<'_>) {
+        unsafe { (CachingProd::new(self), CachingCons::new(self)) }
+    }
+}
+impl<S: Storage> Split for RateTrackedRb<S> {
+    type Prod = CachingProd<Arc<Self>>;
+    type Cons = CachingCons<Arc<Self>>;
+
+    fn split(self) -> (Self::Prod, Self::Cons) {
+        let arc = Arc::new(self);
+        unsafe { (CachingProd::new(arc.clone()), CachingCons::new(arc)) }
+    }
+}
+
+impl_producer_traits!(RateTrackedRb<S: Storage>);
+impl_consumer_traits!(RateTrackedRb<S: Storage>);
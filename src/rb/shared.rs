@@ -1,4 +1,8 @@
-use super::{macros::rb_impl_init, utils::ranges};
+use super::{
+    macros::{rb_impl_init, rb_impl_policy_push},
+    utils::{atomic_index, ranges, AtomicIndex},
+    Policy,
+};
 #[cfg(feature = "alloc")]
 use crate::traits::Split;
 use crate::{
@@ -9,18 +13,28 @@ use crate::{
 };
 #[cfg(feature = "alloc")]
 use alloc::sync::Arc;
+#[cfg(feature = "stats")]
+use core::sync::atomic::AtomicUsize;
 use core::{
     mem::{ManuallyDrop, MaybeUninit},
     num::NonZeroUsize,
     ptr,
-    sync::atomic::{AtomicUsize, Ordering},
+    sync::atomic::{AtomicBool, Ordering},
 };
-use crossbeam_utils::CachePadded;
 
 /// Ring buffer that can be shared between threads.
 ///
 /// Note that there is no explicit requirement of `T: Send`. Instead [`Rb`] will work just fine even with `T: !Send`
 /// until you try to send its [`Prod`] or [`Cons`] to another thread.
+///
+/// Thread safety of the halves is derived automatically from the ring buffer reference they hold.
+/// E.g. a half holding `Arc<SharedRb<S>>` is `Send` because `Arc<SharedRb<S>>` is `Send`,
+/// which in turn requires the storage to be `Sync`, and the storage is `Sync` when `S::Item: Send`
+/// (each item is accessed either by the producer or by the consumer, so it is only moved between threads).
+/// The halves themselves are never `Sync` (see the examples below), because sharing a half would allow simultaneous access to the same items.
+///
+/// `read` and `write` indices are padded to the cache line length to avoid false sharing between producer and consumer.
+/// The padding can be removed by enabling `compact` feature which reduces the size of the ring buffer at the cost of throughput.
 #[cfg_attr(
     feature = "std",
     doc = r##"
@@ -39,12 +53,50 @@ thread::spawn(move || {
 })
 .join();
 ```
+
+```compile_fail
+use ringbuf::{SharedRb, storage::Heap, traits::*};
+
+fn assert_sync<T: Sync>(_: &T) {}
+
+let (prod, _cons) = SharedRb::<Heap<i32>>::new(2).split();
+assert_sync(&prod);
+```
+
+```compile_fail
+use ringbuf::{SharedRb, storage::Heap, traits::*};
+
+fn assert_sync<T: Sync>(_: &T) {}
+
+let (_prod, cons) = SharedRb::<Heap<i32>>::new(2).split();
+assert_sync(&cons);
+```
+
+```compile_fail
+use std::sync::Arc;
+use ringbuf::{SharedRb, Cons, storage::Heap};
+
+fn assert_sync<T: Sync>(_: &T) {}
+
+let rb = Arc::new(SharedRb::<Heap<i32>>::new(2));
+let cons = unsafe { Cons::new(rb) };
+assert_sync(&cons);
+```
 "##
 )]
 pub struct SharedRb<S: Storage> {
     storage: Shared<S>,
-    read: CachePadded<AtomicUsize>,
-    write: CachePadded<AtomicUsize>,
+    read: AtomicIndex,
+    write: AtomicIndex,
+    read_held: AtomicBool,
+    write_held: AtomicBool,
+    read_released: AtomicBool,
+    policy: Policy,
+    leak_on_drop: bool,
+    #[cfg(feature = "stats")]
+    generation: AtomicUsize,
+    #[cfg(feature = "std")]
+    label: Option<&'static str>,
 }
 
 impl<S: Storage> SharedRb<S> {
@@ -57,8 +109,17 @@ impl<S: Storage> SharedRb<S> {
     pub unsafe fn from_raw_parts(storage: S, read: usize, write: usize) -> Self {
         Self {
             storage: Shared::new(storage),
-            read: CachePadded::new(AtomicUsize::new(read)),
-            write: CachePadded::new(AtomicUsize::new(write)),
+            read: atomic_index(read),
+            write: atomic_index(write),
+            read_held: AtomicBool::new(false),
+            write_held: AtomicBool::new(false),
+            read_released: AtomicBool::new(false),
+            policy: Policy::Reject,
+            leak_on_drop: false,
+            #[cfg(feature = "stats")]
+            generation: AtomicUsize::new(0),
+            #[cfg(feature = "std")]
+            label: None,
         }
     }
     /// Destructures ring buffer into underlying storage and `read` and `write` indices.
@@ -74,6 +135,183 @@ impl<S: Storage> SharedRb<S> {
             this.write.load(Ordering::Acquire),
         )
     }
+    /// Destructures ring buffer into underlying storage and `read` and `write` indices.
+    ///
+    /// Unlike [`Self::into_raw_parts`] this method is safe: items that remain in the storage are not dropped (leaked)
+    /// unless the ring buffer is restored back with [`Self::attach`].
+    ///
+    /// This pair of methods can be used to pass ring buffer state through FFI:
+    ///
+    /// ```
+    /// # use ringbuf::{SharedRb, storage::Static, traits::*};
+    /// let mut rb = SharedRb::<Static<i32, 4>>::default();
+    /// rb.push_slice(&[1, 2, 3]);
+    ///
+    /// let (storage, read, write) = rb.detach();
+    /// // ... pass `storage`, `read` and `write` through FFI boundary ...
+    /// let rb = unsafe { SharedRb::attach(storage, read, write) }.unwrap();
+    ///
+    /// assert_eq!(rb.iter().collect::<Vec<_>>(), [&1, &2, &3]);
+    /// ```
+    pub fn detach(self) -> (S, usize, usize) {
+        unsafe { self.into_raw_parts() }
+    }
+    /// Constructs ring buffer from storage and indices checking that indices are valid.
+    ///
+    /// `read` and `write` must be less than twice the storage length and the distance between them must not exceed the storage length.
+    /// Otherwise the storage is returned back in `Err`.
+    ///
+    /// # Safety
+    ///
+    /// The items in storage inside `read..write` range must be initialized, items outside this range must be uninitialized.
+    pub unsafe fn attach(storage: S, read: usize, write: usize) -> Result<Self, S> {
+        let internal = storage.into_internal();
+        let capacity = S::len(&internal);
+        let storage = S::from_internal(internal);
+        let modulus = 2 * capacity;
+        if capacity == 0 || read >= modulus || write >= modulus || (modulus + write - read) % modulus > capacity {
+            return Err(storage);
+        }
+        Ok(Self::from_raw_parts(storage, read, write))
+    }
+    /// Creates an empty ring buffer from storage with specified overflow policy.
+    ///
+    /// The policy is applied to [`Producer`] methods called on the ring buffer itself.
+    /// Producers obtained by splitting the ring buffer always reject items when it is full
+    /// because they cannot remove items concurrently with the consumer.
+    ///
+    /// Note that with [`Policy::Overwrite`] bulk pushes consume all provided items, see its documentation for details.
+    pub fn with_policy(storage: S, policy: Policy) -> Self {
+        let mut this = unsafe { Self::from_raw_parts(storage, 0, 0) };
+        this.policy = policy;
+        this
+    }
+    /// Creates an empty ring buffer from storage with a label.
+    ///
+    /// The label is shown in [`Debug`](`core::fmt::Debug`) output and in panic messages
+    /// of the ring buffer and its producer and consumer (see [`Observer::label`]).
+    #[cfg(feature = "std")]
+    pub fn with_label(storage: S, label: &'static str) -> Self {
+        let mut this = unsafe { Self::from_raw_parts(storage, 0, 0) };
+        this.label = Some(label);
+        this
+    }
+    /// Overflow policy of the ring buffer.
+    pub fn policy(&self) -> Policy {
+        self.policy
+    }
+    /// Sets whether items remaining in the ring buffer are leaked instead of being dropped when the ring buffer is dropped.
+    ///
+    /// **If enabled, destructors of the remaining items are never run, only the storage itself is freed.**
+    /// It is up to the caller to ensure that the items are owned (and eventually dropped) by someone else,
+    /// e.g. when the items are handles to memory of an arena that outlives the ring buffer. Otherwise the items are leaked.
+    ///
+    /// Items removed from the ring buffer by consumer are not affected by this flag and are dropped as usual.
+    pub fn set_leak_on_drop(&mut self, leak: bool) {
+        self.leak_on_drop = leak;
+    }
+    /// Whether items remaining in the ring buffer are leaked when the ring buffer is dropped.
+    pub fn leaks_on_drop(&self) -> bool {
+        self.leak_on_drop
+    }
+    /// Capacity of the ring buffer.
+    ///
+    /// Same as [`Observer::capacity`] but doesn't require the trait to be imported.
+    #[inline]
+    pub fn capacity(&self) -> NonZeroUsize {
+        Observer::capacity(self)
+    }
+    /// The number of items stored in the ring buffer.
+    ///
+    /// Same as [`Observer::occupied_len`] but doesn't require the trait to be imported.
+    ///
+    /// ```
+    /// # extern crate ringbuf;
+    /// # use ringbuf::StaticRb;
+    /// # fn main() {
+    /// let rb = StaticRb::<i32, 4>::default();
+    /// assert_eq!(rb.capacity().get(), 4);
+    /// assert_eq!(rb.len(), 0);
+    /// assert!(rb.is_empty());
+    /// # }
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.occupied_len()
+    }
+    /// Checks if the ring buffer is empty.
+    ///
+    /// Same as [`Observer::is_empty`] but doesn't require the trait to be imported.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        Observer::is_empty(self)
+    }
+    /// Appends an item to the ring buffer.
+    ///
+    /// Same as [`Producer::try_push`] but doesn't require the trait to be imported.
+    /// If the ring buffer is full then the item is handled according to the [policy](`Self::policy`).
+    ///
+    /// It allows to use the ring buffer as a simple queue without splitting it:
+    ///
+    /// ```
+    /// # use ringbuf::{SharedRb, storage::Static};
+    /// let mut rb = SharedRb::<Static<i32, 2>>::default();
+    ///
+    /// assert_eq!(rb.push(1), Ok(()));
+    /// assert_eq!(rb.push(2), Ok(()));
+    /// assert_eq!(rb.push(3), Err(3));
+    ///
+    /// assert_eq!(rb.pop(), Some(1));
+    /// assert_eq!(rb.push(3), Ok(()));
+    /// assert_eq!(rb.pop(), Some(2));
+    /// assert_eq!(rb.pop(), Some(3));
+    /// assert_eq!(rb.pop(), None);
+    /// ```
+    #[inline]
+    pub fn push(&mut self, elem: S::Item) -> Result<(), S::Item> {
+        self.try_push(elem)
+    }
+    /// Removes the oldest item from the ring buffer.
+    ///
+    /// Same as [`Consumer::try_pop`] but doesn't require the trait to be imported.
+    #[inline]
+    pub fn pop(&mut self) -> Option<S::Item> {
+        self.try_pop()
+    }
+    /// Same as [`SplitRef::split_ref`] but returns `None` if producer or consumer of the ring buffer is still marked as held.
+    ///
+    /// Since this method takes `&mut self`, the borrow checker already guarantees that no halves are alive.
+    /// So it can return `None` only if a previously created half was leaked using [`core::mem::forget`] and never dropped.
+    pub fn try_split_ref(&mut self) -> Option<(CachingProd<&Self>, CachingCons<&Self>)> {
+        if self.read_is_held() || self.write_is_held() {
+            None
+        } else {
+            Some(self.split_ref())
+        }
+    }
+    /// Splits a ring buffer with `'static` lifetime into producer and consumer without reference counting.
+    ///
+    /// The halves hold `&'static Self`, so the ring buffer is never dropped.
+    /// Such a reference may be obtained by leaking the ring buffer (e.g. using [`Box::leak`](`alloc::boxed::Box::leak`)).
+    /// A plain `static` cannot provide a mutable reference, so a `static` ring buffer must be a `static mut` (see [`Self::new_static`])
+    /// accessed in `unsafe` code that guarantees the reference is taken only once.
+    pub fn split_static(&'static mut self) -> (CachingProd<&'static Self>, CachingCons<&'static Self>) {
+        let this: &'static Self = self;
+        unsafe { (CachingProd::new(this), CachingCons::new(this)) }
+    }
+    /// Number of times the `write` index has been moved since the ring buffer creation.
+    ///
+    /// It is incremented once per producer commit (e.g. once per [`Producer::try_push`] or [`Producer::push_slice`] call),
+    /// and isn't affected by the consumer. The counter wraps around on overflow.
+    #[cfg(feature = "stats")]
+    pub fn generation(&self) -> usize {
+        self.generation.load(Ordering::Acquire)
+    }
+    /// Checks whether new items have been pushed since [`Self::generation`] returned `generation`.
+    #[cfg(feature = "stats")]
+    pub fn changed_since(&self, generation: usize) -> bool {
+        self.generation() != generation
+    }
 }
 
 impl<S: Storage> Observer for SharedRb<S> {
@@ -92,6 +330,16 @@ impl<S: Storage> Observer for SharedRb<S> {
     fn write_index(&self) -> usize {
         self.write.load(Ordering::Acquire)
     }
+    #[inline]
+    fn read_index_relaxed(&self) -> usize {
+        self.read.load(Ordering::Relaxed)
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn label(&self) -> Option<&'static str> {
+        self.label
+    }
 
     unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&mut [MaybeUninit<S::Item>], &mut [MaybeUninit<S::Item>]) {
         let (first, second) = ranges(self.capacity(), start, end);
@@ -103,7 +351,11 @@ impl<S: Storage> Producer for SharedRb<S> {
     #[inline]
     unsafe fn set_write_index(&self, value: usize) {
         self.write.store(value, Ordering::Release);
+        #[cfg(feature = "stats")]
+        self.generation.fetch_add(1, Ordering::Release);
     }
+
+    rb_impl_policy_push!();
 }
 
 impl<S: Storage> Consumer for SharedRb<S> {
@@ -113,11 +365,38 @@ impl<S: Storage> Consumer for SharedRb<S> {
     }
 }
 
-impl<S: Storage> RingBuffer for SharedRb<S> {}
+impl<S: Storage> RingBuffer for SharedRb<S> {
+    #[inline]
+    fn read_is_held(&self) -> bool {
+        self.read_held.load(Ordering::Relaxed)
+    }
+    #[inline]
+    fn write_is_held(&self) -> bool {
+        self.write_held.load(Ordering::Relaxed)
+    }
+    #[inline]
+    fn read_is_released(&self) -> bool {
+        self.read_released.load(Ordering::Relaxed)
+    }
+    #[inline]
+    unsafe fn hold_read(&self, flag: bool) -> bool {
+        let prev = self.read_held.swap(flag, Ordering::Relaxed);
+        if flag || prev {
+            self.read_released.store(!flag, Ordering::Relaxed);
+        }
+        prev
+    }
+    #[inline]
+    unsafe fn hold_write(&self, flag: bool) -> bool {
+        self.write_held.swap(flag, Ordering::Relaxed)
+    }
+}
 
 impl<S: Storage> Drop for SharedRb<S> {
     fn drop(&mut self) {
-        self.clear();
+        if !self.leak_on_drop {
+            self.clear();
+        }
     }
 }
 
@@ -142,5 +421,30 @@ impl<S: Storage> SplitRef for SharedRb<S> {
 
 rb_impl_init!(SharedRb);
 
+impl<T, const N: usize> SharedRb<Static<T, N>> {
+    /// Creates an empty ring buffer with capacity of `N` items at compile time.
+    ///
+    /// Unlike [`Default::default`] it can be used to initialize a `static` without any runtime initialization.
+    ///
+    /// *Zero capacity is rejected at compile time.*
+    pub const fn new_static() -> Self {
+        const { assert!(N > 0, "ring buffer capacity must be greater than zero") };
+        Self {
+            storage: Shared::uninit(),
+            read: atomic_index(0),
+            write: atomic_index(0),
+            read_held: AtomicBool::new(false),
+            write_held: AtomicBool::new(false),
+            read_released: AtomicBool::new(false),
+            policy: Policy::Reject,
+            leak_on_drop: false,
+            #[cfg(feature = "stats")]
+            generation: AtomicUsize::new(0),
+            #[cfg(feature = "std")]
+            label: None,
+        }
+    }
+}
+
 impl_producer_traits!(SharedRb<S: Storage>);
 impl_consumer_traits!(SharedRb<S: Storage>);
@@ -0,0 +1,86 @@
+use crate::{
+    halves::{CachingCons, CachingProd},
+    rb::SharedRb,
+    storage::Heap,
+    traits::{Consumer, Producer, Split, SplitRef},
+};
+use alloc::sync::Arc;
+
+/// Underlying ring buffer of [`StreamRb`], `None` is used as the end marker.
+type Base<T> = SharedRb<Heap<Option<T>>>;
+
+/// Ring buffer of items followed by an end marker.
+///
+/// The producer signals the end of the stream by [`StreamProd::close`] and the consumer receives it after all pushed items.
+///
+/// One place in the ring buffer is always reserved for the end marker, so closing never fails,
+/// but **the ring buffer can hold at most `capacity - 1` items**.
+pub struct StreamRb<T> {
+    base: Base<T>,
+}
+
+/// Producer of [`StreamRb`].
+pub struct StreamProd<P> {
+    base: P,
+}
+
+/// Consumer of [`StreamRb`].
+pub struct StreamCons<C> {
+    base: C,
+}
+
+impl<T> StreamRb<T> {
+    /// Creates a new stream ring buffer which can hold `capacity - 1` items and the end marker.
+    ///
+    /// *Panics if allocation failed or `capacity` is less than `2`.*
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity >= 2);
+        Self {
+            base: SharedRb::new(capacity),
+        }
+    }
+    /// Splits the ring buffer into producer and consumer.
+    pub fn split(self) -> (StreamProd<CachingProd<Arc<Base<T>>>>, StreamCons<CachingCons<Arc<Base<T>>>>) {
+        let (prod, cons) = self.base.split();
+        (StreamProd { base: prod }, StreamCons { base: cons })
+    }
+    /// Splits the ring buffer into producer and consumer borrowing the ring buffer.
+    pub fn split_ref(&mut self) -> (StreamProd<CachingProd<&Base<T>>>, StreamCons<CachingCons<&Base<T>>>) {
+        let (prod, cons) = self.base.split_ref();
+        (StreamProd { base: prod }, StreamCons { base: cons })
+    }
+}
+
+impl<T, P: Producer<Item = Option<T>>> StreamProd<P> {
+    /// Appends an item to the ring buffer.
+    ///
+    /// If there is no vacant place except the one reserved for the end marker then returns an `Err` containing the item.
+    pub fn try_push(&mut self, item: T) -> Result<(), T> {
+        if self.base.vacant_len() > 1 {
+            let _ = self.base.try_push(Some(item));
+            Ok(())
+        } else {
+            Err(item)
+        }
+    }
+    /// Appends the end marker to the ring buffer.
+    ///
+    /// Never fails because a vacant place is reserved for the marker.
+    pub fn close(mut self) {
+        let r = self.base.try_push(None);
+        debug_assert!(r.is_ok());
+    }
+}
+
+impl<T, C: Consumer<Item = Option<T>>> StreamCons<C> {
+    /// Removes the oldest item from the ring buffer.
+    ///
+    /// Returns `None` if the ring buffer is empty, `Some(Some(item))` for an item and `Some(None)` for the end marker.
+    pub fn pop(&mut self) -> Option<Option<T>> {
+        self.base.try_pop()
+    }
+    /// The number of items and the end marker (if pushed) stored in the ring buffer.
+    pub fn occupied_len(&self) -> usize {
+        self.base.occupied_len()
+    }
+}
@@ -7,7 +7,7 @@ pub unsafe trait RbRef: Clone {
     fn deref(&self) -> &Self::Target;
 }
 
-unsafe impl<'a, B: RingBuffer> RbRef for &'a B {
+unsafe impl<B: RingBuffer> RbRef for &B {
     type Target = B;
     fn deref(&self) -> &Self::Target {
         self
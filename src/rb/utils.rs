@@ -1,4 +1,10 @@
-use core::{num::NonZeroUsize, ops::Range};
+use crate::{
+    delegate_observer,
+    traits::{Observer, Producer, RingBuffer},
+};
+use core::{num::NonZeroUsize, ops::Range, sync::atomic::AtomicUsize};
+#[cfg(not(feature = "compact"))]
+use crossbeam_utils::CachePadded;
 
 /// Returns a pair of ranges between `start` and `end` indices in a ring buffer with specific `capacity`.
 ///
@@ -16,3 +22,43 @@ pub fn ranges(capacity: NonZeroUsize, start: usize, end: usize) -> (Range<usize>
         (head_rem..capacity.get(), 0..tail_rem)
     }
 }
+
+/// Atomic index of a ring buffer shared between threads.
+///
+/// It is padded to the cache line length to avoid false sharing between producer and consumer
+/// unless `compact` feature is enabled.
+#[cfg(not(feature = "compact"))]
+pub type AtomicIndex = CachePadded<AtomicUsize>;
+/// Atomic index of a ring buffer shared between threads.
+///
+/// It isn't padded because `compact` feature is enabled.
+#[cfg(feature = "compact")]
+pub type AtomicIndex = AtomicUsize;
+
+/// Creates a new [`AtomicIndex`].
+pub const fn atomic_index(value: usize) -> AtomicIndex {
+    #[cfg(not(feature = "compact"))]
+    return CachePadded::new(AtomicUsize::new(value));
+    #[cfg(feature = "compact")]
+    return AtomicUsize::new(value);
+}
+
+/// Producer that doesn't hold the write end of the ring buffer.
+///
+/// Used by ring buffers to call default [`Producer`] methods from their overrides.
+pub struct RawProd<'a, B: RingBuffer>(pub &'a B);
+
+impl<'a, B: RingBuffer> RawProd<'a, B> {
+    fn rb(&self) -> &B {
+        self.0
+    }
+}
+impl<'a, B: RingBuffer> Observer for RawProd<'a, B> {
+    delegate_observer!(B, Self::rb);
+}
+impl<'a, B: RingBuffer> Producer for RawProd<'a, B> {
+    #[inline]
+    unsafe fn set_write_index(&self, value: usize) {
+        self.0.set_write_index(value)
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "allocator_api")]
+use alloc::alloc::{Allocator, Global};
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 use core::{cell::UnsafeCell, mem::MaybeUninit, num::NonZeroUsize, ops::Range, slice};
@@ -6,9 +8,15 @@ use core::{cell::UnsafeCell, mem::MaybeUninit, num::NonZeroUsize, ops::Range, sl
 ///
 /// Storage items must be stored as a contiguous array.
 ///
+/// Zero-sized items are supported. In that case no memory is accessed and the storage length only limits the number of items.
+///
 /// # Safety
 ///
-/// *[`Self::len`]/[`Self::is_empty`] must always return the same value.*
+/// *[`Self::len`]/[`Self::is_empty`] must always return the same value unless the internal representation is mutated.*
+///
+/// The ring buffer reads the length once when it takes the storage and then tracks its capacity by itself.
+/// The internal representation is mutated only by methods that explicitly change the capacity (e.g. growing a heap ring buffer
+/// into spare memory), which have exclusive access to the ring buffer and update the tracked capacity accordingly.
 pub unsafe trait Storage {
     type Item: Sized;
 
@@ -33,7 +41,7 @@ pub unsafe trait Storage {
     fn len(this: &Self::Internal) -> usize;
 }
 
-unsafe impl<'a, T> Storage for &'a mut [MaybeUninit<T>] {
+unsafe impl<T> Storage for &mut [MaybeUninit<T>] {
     type Item = T;
 
     type Internal = (*mut MaybeUninit<T>, usize);
@@ -79,7 +87,7 @@ unsafe impl<T, const N: usize> Storage for [MaybeUninit<T>; N] {
     }
 }
 
-#[cfg(feature = "alloc")]
+#[cfg(all(feature = "alloc", not(feature = "allocator_api")))]
 unsafe impl<T> Storage for Vec<MaybeUninit<T>> {
     type Item = T;
 
@@ -103,11 +111,42 @@ unsafe impl<T> Storage for Vec<MaybeUninit<T>> {
     }
 }
 
+#[cfg(feature = "allocator_api")]
+unsafe impl<T, A: Allocator> Storage for Vec<MaybeUninit<T>, A> {
+    type Item = T;
+
+    type Internal = Self;
+
+    fn into_internal(self) -> Self::Internal {
+        self
+    }
+    unsafe fn from_internal(this: Self::Internal) -> Self {
+        this
+    }
+
+    #[inline]
+    fn as_mut_ptr(this: &Self::Internal) -> *mut MaybeUninit<T> {
+        this.as_ptr() as *mut _
+    }
+
+    #[inline]
+    fn len(this: &Self::Internal) -> usize {
+        this.len()
+    }
+}
+
 /// Wrapper for storage that provides multiple write access to it.
+///
+/// Storage length is stored separately, so that it is the logical capacity of the ring buffer
+/// even if the storage has more memory allocated.
 pub(crate) struct Shared<S: Storage> {
     internal: S::Internal,
+    len: NonZeroUsize,
 }
 
+// Items are never accessed from multiple threads simultaneously:
+// access to each item is exclusively owned either by producer or by consumer,
+// so it's enough for items to be moved between threads.
 unsafe impl<S: Storage> Sync for Shared<S> where S::Item: Send {}
 
 impl<S: Storage> Shared<S> {
@@ -116,14 +155,20 @@ impl<S: Storage> Shared<S> {
     /// *Panics if storage is empty.*
     pub fn new(storage: S) -> Self {
         let internal = storage.into_internal();
-        assert!(S::len(&internal) > 0);
-        Self { internal }
+        let len = NonZeroUsize::new(S::len(&internal)).unwrap();
+        Self { internal, len }
     }
 
     /// Get total length of the storage.
     #[inline]
     pub fn len(&self) -> NonZeroUsize {
-        unsafe { NonZeroUsize::new_unchecked(S::len(&self.internal)) }
+        self.len
+    }
+
+    /// Returns a pointer to the beginning of storage.
+    #[inline]
+    pub fn as_mut_ptr(&self) -> *mut MaybeUninit<S::Item> {
+        S::as_mut_ptr(&self.internal)
     }
 
     /// Returns a mutable slice of storage in specified `range`.
@@ -133,8 +178,7 @@ impl<S: Storage> Shared<S> {
     /// Slices with overlapping lifetimes must not overlap.
     #[allow(clippy::mut_from_ref)]
     pub unsafe fn slice(&self, range: Range<usize>) -> &mut [MaybeUninit<S::Item>] {
-        let ptr = S::as_mut_ptr(&self.internal);
-        slice::from_raw_parts_mut(ptr.add(range.start), range.len())
+        slice::from_raw_parts_mut(self.as_mut_ptr().add(range.start), range.len())
     }
 
     /// Returns underlying storage.
@@ -143,6 +187,38 @@ impl<S: Storage> Shared<S> {
     }
 }
 
-pub type Static<T, const N: usize> = [MaybeUninit<T>; N];
+impl<T, const N: usize> Shared<Static<T, N>> {
+    /// Create new uninitialized storage at compile time.
+    pub const fn uninit() -> Self {
+        assert!(N > 0);
+        Self {
+            internal: UnsafeCell::new(crate::utils::uninit_array()),
+            len: unsafe { NonZeroUsize::new_unchecked(N) },
+        }
+    }
+}
+
 #[cfg(feature = "alloc")]
+impl<T> Shared<Vec<MaybeUninit<T>>> {
+    /// Returns underlying vector.
+    pub fn as_vec(&self) -> &Vec<MaybeUninit<T>> {
+        &self.internal
+    }
+    /// Increases the storage length to `len` using memory already allocated by the vector.
+    ///
+    /// Items are neither moved nor dropped, new places are uninitialized.
+    ///
+    /// *Panics if `len` is less than current length or greater than capacity of the vector.*
+    pub fn grow(&mut self, len: usize) {
+        assert!(len >= self.len.get() && len <= self.internal.capacity());
+        self.internal.resize_with(len, MaybeUninit::uninit);
+        self.len = NonZeroUsize::new(len).unwrap();
+    }
+}
+
+pub type Static<T, const N: usize> = [MaybeUninit<T>; N];
+#[cfg(all(feature = "alloc", not(feature = "allocator_api")))]
 pub type Heap<T> = Vec<MaybeUninit<T>>;
+/// Heap storage allocated with allocator `A`.
+#[cfg(feature = "allocator_api")]
+pub type Heap<T, A = Global> = Vec<MaybeUninit<T>, A>;
@@ -244,3 +244,282 @@ fn push_pop() {
     }
     assert_eq!(prod.occupied_len(), 0);
 }
+
+#[test]
+fn layout() {
+    const CAP: usize = 4;
+    let mut rb = Rb::<Static<i32, CAP>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    for i in 0..(3 * CAP) {
+        for _ in 0..=CAP {
+            let (left, right) = cons.occupied_slices();
+            assert_eq!(cons.occupied_layout(), (left.len(), right.len()));
+            let (left, right) = prod.vacant_slices();
+            assert_eq!(prod.vacant_layout(), (left.len(), right.len()));
+
+            let (first, second) = cons.occupied_layout();
+            assert_eq!(first + second, cons.occupied_len());
+            let (first, second) = prod.vacant_layout();
+            assert_eq!(first + second, prod.vacant_len());
+
+            if prod.try_push(0).is_err() {
+                break;
+            }
+        }
+        cons.skip(i % CAP + 1);
+    }
+}
+
+#[test]
+fn until_wrap() {
+    let mut rb = Rb::<Static<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.pushes_until_wrap(), 4);
+    assert_eq!(cons.pops_until_wrap(), 4);
+
+    assert_eq!(prod.push_iter(0..3), 3);
+    assert_eq!(prod.pushes_until_wrap(), 1);
+    assert_eq!(prod.distance_to_full(), 1);
+    assert_eq!(cons.distance_to_empty(), 3);
+
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(cons.pops_until_wrap(), 2);
+
+    assert_eq!(prod.push_iter(3..5), 2);
+    assert_eq!(prod.pushes_until_wrap(), 3);
+    assert_eq!(prod.distance_to_full(), 1);
+
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(cons.pops_until_wrap(), 4);
+    assert_eq!(cons.distance_to_empty(), 1);
+
+    assert_eq!(cons.skip(1), 1);
+    assert_eq!(cons.pops_until_wrap(), 3);
+    assert_eq!(prod.pushes_until_wrap(), 3);
+}
+
+#[test]
+fn slots_before_wrap() {
+    let mut rb = Rb::<Static<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.write_slots_before_wrap(), 4);
+    assert_eq!(cons.read_slots_before_wrap(), 0);
+
+    // Just before the wrap.
+    assert_eq!(prod.push_iter(0..3), 3);
+    assert_eq!(cons.skip(1), 1);
+    assert_eq!(prod.write_slots_before_wrap(), 1);
+    assert_eq!(cons.read_slots_before_wrap(), 2);
+    assert_eq!(prod.write_slots_before_wrap(), prod.vacant_slices().0.len());
+
+    // At the wrap.
+    assert_eq!(prod.push_iter(3..4), 1);
+    assert_eq!(prod.write_slots_before_wrap(), 1);
+    assert_eq!(prod.pushes_until_wrap(), 4);
+    assert_eq!(cons.read_slots_before_wrap(), 3);
+
+    // After the wrap.
+    assert_eq!(prod.push_iter(4..5), 1);
+    assert_eq!(prod.write_slots_before_wrap(), 0);
+    assert_eq!(cons.read_slots_before_wrap(), 3);
+    assert_eq!(cons.read_slots_before_wrap(), cons.as_slices().0.len());
+    assert_eq!(cons.skip(3), 3);
+    assert_eq!(cons.read_slots_before_wrap(), 1);
+    assert_eq!(prod.write_slots_before_wrap(), 3);
+}
+
+#[test]
+fn rewind_read() {
+    let mut rb = Rb::<Static<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_slice(&[0, 1, 2]), 3);
+    assert_eq!(cons.try_pop(), Some(0));
+    assert_eq!(cons.try_pop(), Some(1));
+
+    assert!(unsafe { cons.try_rewind_read(2) });
+    assert!(cons.iter().copied().eq([0, 1, 2]));
+    assert_eq!(prod.occupied_len(), 3);
+}
+
+#[test]
+fn rewind_read_overwritten() {
+    let mut rb = Rb::<Static<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_slice(&[0, 1, 2, 3]), 4);
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(prod.push_slice(&[4]), 1);
+
+    assert!(!unsafe { cons.try_rewind_read(2) });
+    assert!(unsafe { cons.try_rewind_read(1) });
+    assert!(cons.iter().copied().eq([1, 2, 3, 4]));
+}
+
+#[test]
+fn push_with_slices() {
+    let mut rb = Rb::<Static<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_slice(&[0, 1, 2]), 3);
+    assert_eq!(cons.skip(2), 2);
+
+    let count = unsafe {
+        prod.push_with_slices(|left, right| {
+            assert_eq!((left.len(), right.len()), (1, 2));
+            left[0] = MaybeUninit::new(3);
+            right[0] = MaybeUninit::new(4);
+            2
+        })
+    };
+    assert_eq!(count, 2);
+    assert!(cons.iter().copied().eq([2, 3, 4]));
+}
+
+#[test]
+fn consume_with_slices() {
+    let mut rb = Rb::<Static<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_slice(&[0, 1, 2]), 3);
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(prod.push_slice(&[3, 4, 5]), 3);
+
+    let mut items = [0; 3];
+    let count = unsafe {
+        cons.consume_with_slices(|left, right| {
+            assert_eq!((left.len(), right.len()), (2, 2));
+            items[0] = left[0].assume_init_read();
+            items[1] = left[1].assume_init_read();
+            items[2] = right[0].assume_init_read();
+            3
+        })
+    };
+    assert_eq!(count, 3);
+    assert_eq!(items, [2, 3, 4]);
+    assert!(cons.iter().copied().eq([5]));
+}
+
+#[test]
+fn is_contiguous() {
+    let mut rb = Rb::<Static<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert!(cons.is_contiguous());
+    assert_eq!(prod.push_iter(0..4), 4);
+    assert!(cons.is_contiguous());
+
+    assert_eq!(cons.skip(2), 2);
+    assert!(cons.is_contiguous());
+    assert_eq!(prod.push_iter(4..5), 1);
+    assert!(!cons.is_contiguous());
+    assert_eq!(prod.push_iter(5..6), 1);
+    assert!(cons.is_full());
+    assert!(!cons.is_contiguous());
+
+    assert_eq!(cons.skip(2), 2);
+    assert!(cons.is_contiguous());
+    assert_eq!(cons.skip(2), 2);
+    assert!(cons.is_contiguous());
+}
+
+#[test]
+fn as_contiguous_slice() {
+    let mut rb = Rb::<Static<i32, 5>>::default();
+    assert_eq!(rb.as_contiguous_slice(), []);
+
+    assert_eq!(rb.push_iter(0..4), 4);
+    assert_eq!(rb.skip(3), 3);
+    assert_eq!(rb.push_iter(4..8), 4);
+    assert!(!rb.is_contiguous());
+
+    assert_eq!(rb.as_contiguous_slice(), [3, 4, 5, 6, 7]);
+    assert!(rb.is_contiguous());
+    let (read, write) = (rb.read_index(), rb.write_index());
+    let ptr = rb.as_contiguous_slice().as_ptr();
+
+    assert_eq!(rb.as_contiguous_slice(), [3, 4, 5, 6, 7]);
+    assert_eq!((rb.read_index(), rb.write_index()), (read, write));
+    assert_eq!(rb.as_contiguous_slice().as_ptr(), ptr);
+
+    assert_eq!(rb.try_pop(), Some(3));
+    assert_eq!(rb.try_push(8), Ok(()));
+    assert_eq!(rb.make_contiguous(), [4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn ends() {
+    let mut rb = Rb::<Static<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(cons.ends(), None);
+
+    assert_eq!(prod.try_push(0), Ok(()));
+    let (first, last) = cons.ends().unwrap();
+    assert_eq!((first, last), (&0, &0));
+    assert!(core::ptr::eq(first, last));
+
+    assert_eq!(prod.push_iter(1..3), 2);
+    assert!(cons.is_contiguous());
+    assert_eq!(cons.ends(), Some((&0, &2)));
+
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(prod.push_iter(3..6), 3);
+    assert!(!cons.is_contiguous());
+    assert_eq!(cons.ends(), Some((&2, &5)));
+}
+
+#[test]
+fn reserve_exact_contiguous() {
+    let mut rb = Rb::<Static<i32, 5>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_iter(0..4), 4);
+    assert_eq!(cons.skip(3), 3);
+    assert_eq!(prod.vacant_layout(), (1, 3));
+
+    assert_eq!(prod.reserve_exact_contiguous(2).unwrap_err(), 1);
+    assert_eq!(prod.occupied_len(), 1);
+
+    let slice = prod.reserve_exact_contiguous(1).unwrap();
+    assert_eq!(slice.len(), 1);
+    slice[0] = MaybeUninit::new(4);
+    unsafe { prod.advance_write_index(1) };
+
+    assert_eq!(prod.vacant_layout(), (3, 0));
+    let slice = prod.reserve_exact_contiguous(3).unwrap();
+    for (i, x) in slice.iter_mut().enumerate() {
+        *x = MaybeUninit::new(5 + i as i32);
+    }
+    unsafe { prod.advance_write_index(3) };
+
+    assert_eq!(prod.reserve_exact_contiguous(1).unwrap_err(), 0);
+    assert!(prod.reserve_exact_contiguous(0).is_ok());
+    assert!(cons.iter().copied().eq(3..8));
+}
+
+#[test]
+fn set_indices() {
+    let mut rb = Rb::<Static<i32, 4>>::default();
+    unsafe {
+        // Storage layout after `set_indices(2, 5)` is `[2, 3, 0, 1]`.
+        rb.unsafe_slices(0, 4).0.iter_mut().zip([2, 3, 0, 1]).for_each(|(place, item)| {
+            place.write(item);
+        });
+        rb.set_indices(2, 5);
+    }
+    assert_eq!(rb.as_slices(), (&[0, 1][..], &[2][..]));
+    assert!(!rb.is_contiguous());
+    // Item that isn't in the range anymore must be dropped by hand (here it is `Copy`).
+    unsafe { rb.set_indices(2, 4) };
+    assert_eq!(rb.as_slices(), (&[0, 1][..], &[][..]));
+}
+
+#[test]
+#[should_panic]
+fn set_indices_invalid() {
+    let mut rb = Rb::<Static<i32, 4>>::default();
+    unsafe { rb.set_indices(1, 6) };
+}
@@ -0,0 +1,51 @@
+use super::Rb;
+use crate::{storage::Heap, traits::*};
+use alloc::{
+    alloc::{AllocError, Allocator, Global, Layout},
+    vec::Vec,
+};
+use core::{cell::Cell, ptr::NonNull};
+
+#[derive(Default)]
+struct CountingAlloc {
+    allocated: Cell<usize>,
+    deallocated: Cell<usize>,
+}
+
+unsafe impl Allocator for CountingAlloc {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        self.allocated.set(self.allocated.get() + 1);
+        Global.allocate(layout)
+    }
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.deallocated.set(self.deallocated.get() + 1);
+        Global.deallocate(ptr, layout)
+    }
+}
+
+#[test]
+fn new_in() {
+    let alloc = CountingAlloc::default();
+    let mut rb = Rb::<Heap<i32, &CountingAlloc>>::new_in(3, &alloc);
+    assert_eq!(alloc.allocated.get(), 1);
+    assert_eq!(rb.capacity().get(), 3);
+
+    {
+        let (mut prod, mut cons) = rb.split_ref();
+        assert_eq!(prod.push_iter(0..4), 3);
+        assert_eq!(cons.pop_iter().collect::<Vec<_>>(), [0, 1, 2]);
+    }
+
+    drop(rb);
+    assert_eq!(alloc.allocated.get(), 1);
+    assert_eq!(alloc.deallocated.get(), 1);
+}
+
+#[test]
+fn heap_rb_new_in() {
+    let alloc = CountingAlloc::default();
+    let mut rb = crate::HeapRb::new_in(2, &alloc);
+    assert_eq!(rb.push(1), Ok(()));
+    assert_eq!(rb.pop(), Some(1));
+    assert_eq!(alloc.allocated.get(), 1);
+}
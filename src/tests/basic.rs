@@ -2,6 +2,7 @@ use super::Rb;
 use crate::{
     storage::Static,
     traits::{observer::Observe, *},
+    LocalRb, SharedRb,
 };
 
 fn indices(this: impl Observer) -> (usize, usize) {
@@ -168,3 +169,208 @@ fn len_remaining() {
     assert_eq!(prod.vacant_len(), 1);
     assert_eq!(cons.vacant_len(), 1);
 }
+
+#[test]
+fn push_into() {
+    let mut rb = Rb::<Static<i64, 2>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_into(1i32), Ok(()));
+    assert_eq!(prod.push_into(-2i8), Ok(()));
+    assert_eq!(prod.push_into(3u32), Err(3i64));
+
+    assert_eq!(cons.try_pop(), Some(1));
+    assert_eq!(cons.try_pop(), Some(-2));
+}
+
+#[test]
+fn push_len() {
+    let mut rb = Rb::<Static<i32, 3>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_len(1), Ok(1));
+    assert_eq!(prod.push_len(2), Ok(2));
+    assert_eq!(prod.push_len(3), Ok(3));
+    assert_eq!(prod.push_len(4), Err(4));
+
+    assert_eq!(cons.try_pop(), Some(1));
+    assert_eq!(prod.push_len(4), Ok(3));
+}
+
+#[test]
+fn default() {
+    let local = LocalRb::<Static<i32, 4>>::default();
+    assert_eq!(local.capacity().get(), 4);
+    assert!(local.is_empty());
+    assert_eq!(indices(local), (0, 0));
+
+    let shared = SharedRb::<Static<i32, 4>>::default();
+    assert_eq!(shared.capacity().get(), 4);
+    assert!(shared.is_empty());
+    assert_eq!(indices(shared), (0, 0));
+}
+
+#[test]
+fn push_wrap() {
+    const CAP: usize = 3;
+    let mut rb = Rb::<Static<i32, CAP>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    for i in 0..(4 * CAP as i32) {
+        assert_eq!(prod.try_push(2 * i), Ok(()));
+        assert_eq!(prod.try_push(2 * i + 1), Ok(()));
+        assert_eq!(indices(prod.observe()).1, (2 * i as usize + 2) % (2 * CAP));
+        assert_eq!(cons.try_pop(), Some(2 * i));
+        assert_eq!(cons.try_pop(), Some(2 * i + 1));
+    }
+    assert!(cons.is_empty());
+}
+
+#[test]
+fn pop_wrap() {
+    const CAP: usize = 3;
+    let mut rb = Rb::<Static<i32, CAP>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.try_push(-1), Ok(()));
+    for i in 0..(4 * CAP as i32) {
+        assert_eq!(prod.try_push(i), Ok(()));
+        assert_eq!(cons.try_pop(), Some(i - 1));
+        assert_eq!(indices(cons.observe()).0, (i as usize + 1) % (2 * CAP));
+    }
+    assert_eq!(cons.try_pop(), Some(4 * CAP as i32 - 1));
+    assert_eq!(cons.try_pop(), None);
+}
+
+#[test]
+fn push_or_register() {
+    let mut rb = Rb::<Static<i32, 1>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    let mut registered = 0;
+
+    assert_eq!(prod.push_or_register(1, || registered += 1), Ok(()));
+    assert_eq!(registered, 0);
+    assert_eq!(prod.push_or_register(2, || registered += 1), Err(2));
+    assert_eq!(registered, 1);
+
+    assert_eq!(cons.try_pop(), Some(1));
+    assert_eq!(prod.push_or_register(3, || registered += 1), Ok(()));
+    assert_eq!(registered, 1);
+}
+
+#[test]
+fn detach_attach() {
+    let mut rb = SharedRb::<Static<i32, 3>>::default();
+    rb.push_slice(&[1, 2, 3]);
+    rb.skip(2);
+    rb.push_slice(&[4, 5]);
+
+    let (storage, read, write) = rb.detach();
+    assert_eq!((read, write), (2, 5));
+    let mut rb = unsafe { SharedRb::attach(storage, read, write) }.ok().unwrap();
+    assert_eq!(rb.try_pop(), Some(3));
+    assert_eq!(rb.try_pop(), Some(4));
+    assert_eq!(rb.try_pop(), Some(5));
+    assert_eq!(rb.try_pop(), None);
+}
+
+#[test]
+fn attach_invalid() {
+    let storage = || crate::utils::uninit_array::<i32, 3>();
+    for (read, write) in [(6, 0), (0, 6), (0, 4), (5, 3), (1, 5)] {
+        assert!(unsafe { SharedRb::attach(storage(), read, write) }.is_err());
+    }
+    for (read, write) in [(0, 0), (0, 3), (5, 0), (4, 1), (2, 5)] {
+        assert!(unsafe { SharedRb::attach(storage(), read, write) }.is_ok());
+    }
+}
+
+#[test]
+fn min_static_capacity() {
+    let mut rb = Rb::<Static<i32, 1>>::default();
+    assert_eq!(rb.capacity().get(), 1);
+    assert_eq!(rb.try_push(1), Ok(()));
+    assert_eq!(rb.try_push(2), Err(2));
+    assert_eq!(rb.try_pop(), Some(1));
+}
+
+#[test]
+fn halves_indices() {
+    const CAP: usize = 3;
+    let mut rb = Rb::<Static<i32, CAP>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    for _ in 0..(4 * CAP) {
+        prod.push_iter(0..2);
+        assert_eq!(cons.skip(1), 1);
+        for (read, write) in [(cons.read_index(), cons.write_index()), (prod.read_index(), prod.write_index())] {
+            assert!(read < 2 * CAP && write < 2 * CAP);
+            assert_eq!((2 * CAP + write - read) % (2 * CAP), cons.occupied_len());
+        }
+    }
+}
+
+#[test]
+fn recycle() {
+    #[derive(Debug, PartialEq)]
+    struct Resource {
+        id: usize,
+        dirty: bool,
+    }
+    let reset = |r: &mut Resource| r.dirty = false;
+
+    let mut rb = Rb::<Static<Resource, 2>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(prod.recycle(Resource { id: 0, dirty: true }, reset), Ok(()));
+    assert_eq!(prod.recycle(Resource { id: 1, dirty: false }, reset), Ok(()));
+    assert_eq!(
+        prod.recycle(Resource { id: 2, dirty: true }, |_| unreachable!()),
+        Err(Resource { id: 2, dirty: true })
+    );
+
+    let mut resource = cons.try_pop().unwrap();
+    assert_eq!(resource, Resource { id: 0, dirty: false });
+    resource.dirty = true;
+    assert_eq!(prod.recycle(resource, reset), Ok(()));
+    assert_eq!(cons.try_pop(), Some(Resource { id: 1, dirty: false }));
+    assert_eq!(cons.try_pop(), Some(Resource { id: 0, dirty: false }));
+}
+
+#[test]
+fn push_ref() {
+    let mut rb = Rb::<Static<i32, 2>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.try_push(0), Ok(()));
+    {
+        let mut item = prod.push_ref(1).ok().unwrap();
+        assert_eq!(*item, 1);
+        *item += 10;
+        // Item is not visible to the consumer until the handle is dropped.
+        assert_eq!(cons.occupied_len(), 1);
+        assert_eq!(cons.try_pop(), Some(0));
+        assert_eq!(cons.try_pop(), None);
+        assert_eq!(*item, 11);
+    }
+    assert_eq!(prod.occupied_len(), 1);
+    assert_eq!(prod.try_push(2), Ok(()));
+    assert!(prod.push_ref(3).is_err());
+
+    assert_eq!(cons.try_pop(), Some(11));
+    assert_eq!(cons.try_pop(), Some(2));
+}
+
+#[test]
+fn new_static() {
+    static mut RB: SharedRb<Static<i32, 2>> = SharedRb::new_static();
+
+    let rb = unsafe { &mut *core::ptr::addr_of_mut!(RB) };
+    assert_eq!(rb.capacity().get(), 2);
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(prod.try_push(0), Ok(()));
+    assert_eq!(prod.try_push(1), Ok(()));
+    assert_eq!(prod.try_push(2), Err(2));
+    assert_eq!(cons.try_pop(), Some(0));
+    assert_eq!(cons.try_pop(), Some(1));
+    assert_eq!(cons.try_pop(), None);
+}
@@ -0,0 +1,66 @@
+use crate::{rb::DoubleBuffer, traits::*};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+};
+
+const LEN: usize = 16;
+
+#[test]
+fn single_writer() {
+    let db = Arc::new(DoubleBuffer::<i32>::new(LEN));
+    let writer = db.writer().unwrap();
+    assert!(db.writer().is_none());
+    drop(writer);
+    assert!(db.writer().is_some());
+}
+
+#[test]
+fn publish() {
+    let db = Arc::new(DoubleBuffer::<i32>::new(LEN));
+    let mut writer = db.writer().unwrap();
+
+    writer.back().push_iter(0..3);
+    assert!(db.front().is_empty());
+
+    writer.publish();
+    assert!(db.front().iter().copied().eq(0..3));
+    assert!(writer.back().is_empty());
+}
+
+#[test]
+fn concurrent() {
+    let db = Arc::new(DoubleBuffer::<usize>::new(LEN));
+    let done = Arc::new(AtomicBool::new(false));
+
+    let wjh = thread::spawn({
+        let mut writer = db.writer().unwrap();
+        let done = done.clone();
+        move || {
+            for i in 0..1000 {
+                let back = writer.back();
+                back.clear();
+                // Every published buffer is full of the same value.
+                back.push_iter((0..LEN).map(|_| i));
+                writer.publish();
+            }
+            done.store(true, Ordering::SeqCst);
+        }
+    });
+
+    let rjh = thread::spawn(move || {
+        while !done.load(Ordering::SeqCst) {
+            let front = db.front();
+            if let Some(first) = front.iter().next() {
+                assert_eq!(front.occupied_len(), LEN);
+                assert!(front.iter().all(|x| x == first));
+            }
+        }
+    });
+
+    wjh.join().unwrap();
+    rjh.join().unwrap();
+}
@@ -1,7 +1,11 @@
 use super::Rb;
 use crate::{storage::Static, traits::*};
-use alloc::collections::BTreeSet;
-use core::cell::RefCell;
+use alloc::{collections::BTreeSet, vec::Vec};
+use core::{
+    cell::RefCell,
+    mem::MaybeUninit,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 
 #[derive(Debug)]
 struct Dropper<'a> {
@@ -106,3 +110,196 @@ fn transaction() {
     drop(rb);
     assert_eq!(set.borrow().len(), 0);
 }
+
+#[test]
+fn drain_filter() {
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = Rb::<Static<Dropper, 4>>::default();
+    {
+        let (mut prod, mut cons) = rb.split_ref();
+
+        prod.try_push(Dropper::new(&set, 0)).unwrap();
+        prod.try_push(Dropper::new(&set, 1)).unwrap();
+        cons.skip(2);
+        for id in 1..5 {
+            prod.try_push(Dropper::new(&set, id)).unwrap();
+        }
+        assert_eq!(set.borrow().len(), 4);
+
+        let mut iter = cons.drain_filter(|x| x.id % 2 == 0);
+        assert_eq!(iter.next().unwrap().id, 2);
+        assert_eq!(set.borrow().len(), 3);
+        drop(iter);
+        assert!(cons.iter().map(|x| x.id).eq([1, 3, 4]));
+        assert_eq!(set.borrow().len(), 3);
+    }
+
+    drop(rb);
+    assert_eq!(set.borrow().len(), 0);
+}
+
+#[test]
+fn zero_sized() {
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+    struct ZeroSized;
+    impl Drop for ZeroSized {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let mut rb = Rb::<Static<ZeroSized, 4>>::default();
+    assert_eq!(rb.push_iter((0..3).map(|_| ZeroSized)), 3);
+    assert_eq!(DROPS.load(Ordering::SeqCst), 0);
+
+    drop(rb.try_pop());
+    assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+    assert_eq!(rb.skip(1), 1);
+    assert_eq!(DROPS.load(Ordering::SeqCst), 2);
+
+    drop(rb);
+    assert_eq!(DROPS.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn leak_on_drop() {
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = Rb::<Static<Dropper, 3>>::default();
+    assert!(!rb.leaks_on_drop());
+    rb.set_leak_on_drop(true);
+    assert!(rb.leaks_on_drop());
+
+    {
+        let (mut prod, mut cons) = rb.split_ref();
+        prod.try_push(Dropper::new(&set, 1)).unwrap();
+        prod.try_push(Dropper::new(&set, 2)).unwrap();
+        prod.try_push(Dropper::new(&set, 3)).unwrap();
+
+        cons.try_pop().unwrap();
+        assert_eq!(set.borrow().len(), 2);
+    }
+
+    drop(rb);
+    assert_eq!(*set.borrow(), BTreeSet::from([2, 3]));
+}
+
+#[test]
+fn drain_all() {
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = Rb::<Static<Dropper, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    for id in 0..4 {
+        prod.try_push(Dropper::new(&set, id)).unwrap();
+    }
+    cons.try_pop().unwrap();
+    prod.try_push(Dropper::new(&set, 4)).unwrap();
+    assert!(!cons.is_contiguous());
+
+    let items = cons.drain_all();
+    assert!(cons.is_empty());
+    assert_eq!(set.borrow().len(), 4);
+    assert_eq!(items.iter().map(|d| d.id).collect::<Vec<_>>(), [1, 2, 3, 4]);
+
+    drop(items);
+    assert_eq!(set.borrow().len(), 0);
+}
+
+#[test]
+fn take_slice() {
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = Rb::<Static<Dropper, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    for id in 0..4 {
+        prod.try_push(Dropper::new(&set, id)).unwrap();
+    }
+    cons.try_pop().unwrap();
+    cons.try_pop().unwrap();
+    prod.try_push(Dropper::new(&set, 4)).unwrap();
+    assert!(!cons.is_contiguous());
+
+    let mut out = [const { MaybeUninit::uninit() }; 2];
+    assert_eq!(cons.take_slice(&mut out), 2);
+    assert_eq!(set.borrow().len(), 3);
+    let out = out.map(|item| unsafe { item.assume_init() });
+    assert_eq!(out.iter().map(|d| d.id).collect::<Vec<_>>(), [2, 3]);
+    assert_eq!(cons.iter().map(|d| d.id).collect::<Vec<_>>(), [4]);
+
+    let mut out = [const { MaybeUninit::uninit() }; 3];
+    assert_eq!(cons.take_slice(&mut out), 1);
+    assert!(cons.is_empty());
+    assert_eq!(set.borrow().len(), 3);
+    let item = unsafe { out[0].assume_init_read() };
+    assert_eq!(item.id, 4);
+
+    drop(item);
+    assert_eq!(set.borrow().len(), 2);
+}
+
+#[test]
+fn push_vec() {
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = Rb::<Static<Dropper, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    prod.try_push(Dropper::new(&set, 0)).unwrap();
+    prod.try_push(Dropper::new(&set, 1)).unwrap();
+    cons.try_pop().unwrap();
+
+    let mut items = (2..7).map(|id| Dropper::new(&set, id)).collect::<Vec<_>>();
+    assert_eq!(prod.push_vec(&mut items), 3);
+    assert!(!cons.is_contiguous());
+    assert_eq!(set.borrow().len(), 6);
+    assert_eq!(cons.iter().map(|d| d.id).collect::<Vec<_>>(), [1, 2, 3, 4]);
+    assert_eq!(items.iter().map(|d| d.id).collect::<Vec<_>>(), [5, 6]);
+
+    assert_eq!(prod.push_vec(&mut items), 0);
+    assert_eq!(items.len(), 2);
+
+    cons.clear();
+    assert_eq!(set.borrow().len(), 2);
+    assert_eq!(prod.push_vec(&mut items), 2);
+    assert!(items.is_empty());
+    assert_eq!(cons.iter().map(|d| d.id).collect::<Vec<_>>(), [5, 6]);
+
+    drop(items);
+    assert_eq!(set.borrow().len(), 2);
+    cons.clear();
+    assert_eq!(set.borrow().len(), 0);
+}
+
+#[test]
+fn pop_into_vec() {
+    let set = RefCell::new(BTreeSet::new());
+
+    let mut rb = Rb::<Static<Dropper, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    for id in 0..4 {
+        prod.try_push(Dropper::new(&set, id)).unwrap();
+    }
+    cons.try_pop().unwrap();
+    cons.try_pop().unwrap();
+    prod.try_push(Dropper::new(&set, 4)).unwrap();
+    prod.try_push(Dropper::new(&set, 5)).unwrap();
+    assert!(!cons.is_contiguous());
+
+    let mut out = Vec::from([Dropper::new(&set, 10)]);
+    assert_eq!(cons.pop_into_vec(&mut out, 3), 3);
+    assert_eq!(set.borrow().len(), 5);
+    assert_eq!(out.iter().map(|d| d.id).collect::<Vec<_>>(), [10, 2, 3, 4]);
+
+    assert_eq!(cons.pop_into_vec(&mut out, 3), 1);
+    assert_eq!(cons.pop_into_vec(&mut out, 3), 0);
+    assert!(cons.is_empty());
+    assert_eq!(out.iter().map(|d| d.id).collect::<Vec<_>>(), [10, 2, 3, 4, 5]);
+
+    drop(out);
+    assert_eq!(set.borrow().len(), 0);
+}
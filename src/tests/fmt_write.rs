@@ -23,7 +23,7 @@ fn write_overflow() {
 
     assert_eq!(
         write!(prod, "This is a very long string that will overflow the small buffer\n"),
-        Err(core::fmt::Error::default())
+        Err(core::fmt::Error)
     );
 
     assert_eq!(cons.occupied_len(), 10);
@@ -31,9 +31,76 @@ fn write_overflow() {
 
     assert_eq!(
         write!(prod, "{} {} {} {} {}\n", "This", "string", "will", "also", "overflow"),
-        Err(core::fmt::Error::default())
+        Err(core::fmt::Error)
     );
 
     assert_eq!(cons.occupied_len(), 10);
     assert!(cons.pop_iter().eq(b"This strin".iter().copied()));
 }
+
+#[test]
+fn write_overflow_utf8() {
+    let mut rb = Rb::<Static<u8, 7>>::default();
+
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(write!(prod, "{}-{}", 12345, 'λ'), Err(core::fmt::Error));
+    // Only the first byte of the two-byte character fits.
+    assert_eq!(cons.occupied_len(), 7);
+    assert!(cons.pop_iter().eq(b"12345-\xCE".iter().copied()));
+}
+
+#[test]
+fn debug() {
+    extern crate std;
+    use std::format;
+
+    let mut rb = Rb::<Static<i32, 3>>::default();
+    assert_eq!(format!("{:?}", rb), "[]");
+
+    assert_eq!(rb.push_iter(0..2), 2);
+    assert_eq!(rb.skip(2), 2);
+    assert_eq!(rb.push_iter(2..4), 2);
+    assert!(!rb.is_contiguous());
+
+    let name = if cfg!(feature = "test_local") { "LocalRb" } else { "SharedRb" };
+    assert_eq!(format!("{:?}", rb), "[2, 3]");
+    assert_eq!(
+        format!("{:#?}", rb),
+        format!(
+            "{} {{\n    capacity: 3,\n    read_index: 2,\n    write_index: 4,\n    layout: (\n        1,\n        1,\n    ),\n    items: [\n        2,\n        3,\n    ],\n}}",
+            name
+        )
+    );
+
+    let (_, cons) = rb.split_ref();
+    assert_eq!(format!("{:?}", cons), "[2, 3]");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn debug_label() {
+    extern crate std;
+    use crate::SharedRb;
+    use std::format;
+
+    let mut rb = SharedRb::with_label(crate::utils::uninit_array::<i32, 2>(), "queue");
+    assert_eq!(rb.label(), Some("queue"));
+    assert!(rb.try_push(1).is_ok());
+    assert_eq!(format!("{:?}", rb), "[1]");
+    assert!(format!("{:#?}", rb).starts_with("SharedRb {\n    label: \"queue\",\n    capacity: 2,"));
+
+    let (_, cons) = rb.split_ref();
+    assert_eq!(cons.label(), Some("queue"));
+    assert!(format!("{:#?}", cons).contains("label: \"queue\""));
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[should_panic(expected = "ring buffer `queue`: more items consumed than occupied")]
+fn panic_label() {
+    use crate::SharedRb;
+
+    let mut rb = SharedRb::with_label(crate::utils::uninit_array::<i32, 2>(), "queue");
+    unsafe { rb.consume_with_slices(|_, _| 1) };
+}
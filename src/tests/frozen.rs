@@ -114,3 +114,54 @@ fn consumer() {
     assert_eq!(frozen_cons.occupied_len(), 1);
     assert_eq!(prod.occupied_len(), 1);
 }
+
+#[test]
+fn cached_index() {
+    let rb = Rb::<Static<i32, 4>>::default();
+    let (mut frozen_prod, mut frozen_cons) = unsafe { (FrozenProd::new(&rb), FrozenCons::new(&rb)) };
+
+    assert_eq!(frozen_prod.push_iter(0..3), 3);
+    assert_eq!((frozen_prod.cached_index(), frozen_prod.shared_index()), (3, 0));
+    frozen_prod.sync();
+    assert_eq!((frozen_prod.cached_index(), frozen_prod.shared_index()), (3, 3));
+
+    frozen_cons.sync();
+    assert_eq!(frozen_cons.skip(2), 2);
+    assert_eq!((frozen_cons.cached_index(), frozen_cons.shared_index()), (2, 0));
+    frozen_cons.sync();
+    assert_eq!((frozen_cons.cached_index(), frozen_cons.shared_index()), (2, 2));
+}
+
+#[test]
+fn publish() {
+    let rb = Rb::<Static<i32, 4>>::default();
+    let (mut frozen_prod, cons) = unsafe { (FrozenProd::new(&rb), <Rb<_> as SplitRef>::RefCons::new(&rb)) };
+
+    assert_eq!(frozen_prod.push_iter(0..3), 3);
+    assert!(cons.is_empty());
+    frozen_prod.publish();
+    assert!(cons.iter().copied().eq(0..3));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn freeze() {
+    let mut rb = Rb::<Static<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_iter(0..4), 4);
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(prod.push_iter(4..6), 2);
+    assert!(!cons.as_slices().1.is_empty());
+
+    let frozen = cons.freeze();
+    assert_eq!(frozen.as_slice(), [2, 3, 4, 5]);
+    assert_eq!(cons.occupied_len(), 4);
+
+    let copy = frozen.clone();
+    assert_eq!(cons.skip(3), 3);
+    assert_eq!(prod.push_iter(6..9), 3);
+    assert!(cons.iter().copied().eq(5..9));
+    assert_eq!(*frozen, [2, 3, 4, 5]);
+    assert_eq!(*copy, [2, 3, 4, 5]);
+}
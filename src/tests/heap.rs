@@ -0,0 +1,162 @@
+use super::Rb;
+use crate::{storage::Heap, traits::*, LocalRb};
+use alloc::{collections::VecDeque, vec};
+use core::iter;
+
+#[test]
+fn byte_capacity() {
+    let rb = Rb::<Heap<u8>>::with_byte_capacity(10);
+    assert_eq!(rb.capacity().get(), 10);
+}
+
+#[test]
+fn byte_budget() {
+    assert_eq!(Rb::<Heap<u8>>::with_byte_budget(10).capacity().get(), 10);
+    assert_eq!(Rb::<Heap<u16>>::with_byte_budget(10).capacity().get(), 5);
+    assert_eq!(Rb::<Heap<u32>>::with_byte_budget(10).capacity().get(), 2);
+    assert_eq!(Rb::<Heap<[u8; 3]>>::with_byte_budget(10).capacity().get(), 3);
+    assert_eq!(Rb::<Heap<u64>>::with_byte_budget(7).capacity().get(), 1);
+    assert_eq!(Rb::<Heap<u64>>::with_byte_budget(0).capacity().get(), 1);
+}
+
+#[test]
+#[should_panic]
+fn byte_budget_zero_sized() {
+    Rb::<Heap<()>>::with_byte_budget(10);
+}
+
+#[test]
+fn vec_deque() {
+    let deque = VecDeque::from([1, 2, 3]);
+    let rb = Rb::<Heap<i32>>::from_vec_deque(deque);
+    assert_eq!(rb.capacity().get(), 3);
+    assert!(rb.iter().copied().eq([1, 2, 3]));
+    assert_eq!(rb.into_vec_deque(), [1, 2, 3]);
+}
+
+#[test]
+fn vec_deque_wrapped() {
+    let mut rb = Rb::<Heap<i32>>::new(3);
+    rb.push_slice(&[0, 1, 2]);
+    rb.skip(2);
+    rb.push_slice(&[3, 4]);
+    assert_eq!(rb.into_vec_deque(), [2, 3, 4]);
+}
+
+#[test]
+fn vec_deque_empty() {
+    let rb = Rb::<Heap<i32>>::from_vec_deque(VecDeque::new());
+    assert_eq!(rb.capacity().get(), 1);
+    assert!(rb.into_vec_deque().is_empty());
+}
+
+#[test]
+fn extend_vec() {
+    let mut rb = Rb::<Heap<i32>>::new(4);
+    rb.push_slice(&[0, 1, 2]);
+    rb.skip(2);
+    rb.push_slice(&[3, 4, 5]);
+
+    let mut out = vec![-1];
+    assert_eq!(rb.extend_vec(&mut out, 3), 3);
+    assert_eq!(out, [-1, 2, 3, 4]);
+    assert_eq!(rb.extend_vec(&mut out, 8), 4);
+    assert_eq!(out, [-1, 2, 3, 4, 2, 3, 4, 5]);
+    assert_eq!(rb.occupied_len(), 4);
+}
+
+#[test]
+fn zero_sized() {
+    let mut rb = Rb::<Heap<()>>::new(3);
+    assert_eq!(rb.capacity().get(), 3);
+
+    assert_eq!(rb.push_iter(iter::repeat_n((), 4)), 3);
+    assert!(rb.is_full());
+    assert_eq!(rb.try_push(()), Err(()));
+    assert_eq!(rb.len(), 3);
+
+    assert_eq!(rb.try_pop(), Some(()));
+    assert_eq!(rb.try_pop(), Some(()));
+    assert_eq!(rb.len(), 1);
+
+    assert_eq!(rb.push_slice(&[(), ()]), 2);
+    assert_eq!(rb.as_slices().0.len() + rb.as_slices().1.len(), 3);
+    assert_eq!(rb.pop_iter().count(), 3);
+    assert!(rb.is_empty());
+}
+
+#[test]
+fn push_grow() {
+    let mut rb = LocalRb::<Heap<i32>>::new(2);
+    rb.push_grow(0);
+    rb.push_grow(1);
+    assert_eq!(rb.capacity().get(), 2);
+
+    assert_eq!(rb.try_pop(), Some(0));
+    rb.push_grow(2);
+    assert!(!rb.is_contiguous());
+
+    rb.push_grow(3);
+    assert_eq!(rb.capacity().get(), 4);
+    assert!(rb.iter().copied().eq(1..4));
+
+    for i in 4..9 {
+        rb.push_grow(i);
+    }
+    assert_eq!(rb.capacity().get(), 8);
+    assert!(rb.iter().copied().eq(1..9));
+
+    rb.push_grow(9);
+    assert_eq!(rb.capacity().get(), 16);
+    assert!(rb.iter().copied().eq(1..10));
+}
+
+#[test]
+fn grow_into_spare() {
+    let mut rb = Rb::<Heap<i32>>::with_capacity_and_spare(3, 5);
+    assert_eq!(rb.capacity().get(), 3);
+    assert!(rb.spare_capacity() >= 5);
+    let ptr = unsafe { rb.unsafe_slices(0, 3).0.as_ptr() };
+
+    assert_eq!(rb.push_iter(0..3), 3);
+    assert_eq!(rb.skip(2), 2);
+    assert_eq!(rb.push_iter(3..5), 2);
+    assert!(!rb.is_contiguous());
+
+    assert_eq!(rb.grow_into_spare(6), Ok(()));
+    assert_eq!(rb.capacity().get(), 6);
+    assert!(rb.iter().copied().eq(2..5));
+    assert_eq!(rb.push_iter(5..8), 3);
+    assert!(rb.is_full());
+    assert!(rb.iter().copied().eq(2..8));
+
+    let max = 6 + rb.spare_capacity();
+    assert_eq!(rb.grow_into_spare(max + 1), Err(max));
+    assert_eq!(rb.grow_into_spare(max), Ok(()));
+    assert_eq!(rb.grow_into_spare(2), Ok(()));
+    assert_eq!(rb.capacity().get(), max);
+    assert_eq!(rb.spare_capacity(), 0);
+    assert!(rb.iter().copied().eq(2..8));
+
+    assert_eq!(unsafe { rb.unsafe_slices(0, 3).0.as_ptr() }, ptr);
+}
+
+#[test]
+fn grow_into_spare_raw_parts() {
+    let mut rb = Rb::<Heap<i32>>::with_capacity_and_spare(2, 2);
+    assert_eq!(rb.push_iter(0..2), 2);
+    assert_eq!(rb.grow_into_spare(3), Ok(()));
+    assert_eq!(rb.try_push(2), Ok(()));
+
+    let (storage, read, write) = unsafe { rb.into_raw_parts() };
+    assert_eq!(storage.len(), 3);
+    let rb = unsafe { Rb::from_raw_parts(storage, read, write) };
+    assert_eq!(rb.capacity().get(), 3);
+    assert!(rb.iter().copied().eq(0..3));
+}
+
+#[test]
+#[should_panic(expected = "capacity with spare overflows usize")]
+fn spare_overflow() {
+    let _ = Rb::<Heap<i32>>::with_capacity_and_spare(2, usize::MAX);
+}
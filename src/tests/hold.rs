@@ -0,0 +1,122 @@
+use super::Rb;
+use crate::{
+    halves::{Cons, Prod, SendError, SendErrorKind},
+    rb::traits::ToRbRef,
+    storage::Static,
+    traits::*,
+    LocalRb, SharedRb,
+};
+use core::mem;
+
+#[test]
+fn hold() {
+    let mut rb = Rb::<Static<i32, 4>>::default();
+    assert!(!rb.read_is_held() && !rb.write_is_held());
+    {
+        let (prod, cons) = rb.split_ref();
+        assert!(prod.rb().write_is_held());
+        assert!(cons.rb().read_is_held());
+
+        drop(prod);
+        assert!(!cons.rb().write_is_held());
+        assert!(cons.rb().read_is_held());
+    }
+    assert!(!rb.read_is_held() && !rb.write_is_held());
+}
+
+#[test]
+fn try_split_ref() {
+    let mut rb = Rb::<Static<i32, 4>>::default();
+
+    let (prod, cons) = rb.try_split_ref().unwrap();
+    drop((prod, cons));
+
+    let (prod, cons) = rb.try_split_ref().unwrap();
+    mem::forget(prod);
+    drop(cons);
+    assert!(rb.try_split_ref().is_none());
+}
+
+#[test]
+fn into_rb_ref() {
+    let mut rb = Rb::<Static<i32, 4>>::default();
+    let (prod, cons) = rb.split_ref();
+    let rb_ref = prod.into_rb_ref();
+    assert!(!rb_ref.write_is_held());
+    assert!(rb_ref.read_is_held());
+    drop(cons);
+    assert!(!rb_ref.read_is_held());
+}
+
+#[test]
+fn send() {
+    let mut rb = SharedRb::<Static<i32, 1>>::default();
+    let (mut prod, cons) = rb.split_ref();
+
+    assert_eq!(prod.send(1), Ok(()));
+    assert_eq!(
+        prod.send(2),
+        Err(SendError {
+            item: 2,
+            reason: SendErrorKind::Full
+        })
+    );
+    drop(cons);
+    assert_eq!(
+        prod.send(3),
+        Err(SendError {
+            item: 3,
+            reason: SendErrorKind::Disconnected
+        })
+    );
+}
+
+#[test]
+fn send_direct() {
+    let mut rb = LocalRb::<Static<i32, 2>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.send(1), Ok(()));
+    assert_eq!(cons.try_pop(), Some(1));
+    drop(cons);
+    assert_eq!(prod.send(2).unwrap_err().reason, SendErrorKind::Disconnected);
+    assert!(prod.is_empty());
+}
+
+#[test]
+fn try_push_iter() {
+    let mut rb = Rb::<Static<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_slice(&[0, 1]), 2);
+    assert_eq!(cons.skip(2), 2);
+    let mut iter = 0..100;
+    assert_eq!(prod.try_push_iter(&mut iter), Ok(4));
+    assert_eq!(iter.start, 4);
+    assert!(cons.pop_iter().eq(0..4));
+
+    drop(cons);
+    assert_eq!(prod.try_push_iter(&mut iter), Err(0));
+    assert_eq!(iter.start, 4);
+    assert!(prod.is_empty());
+}
+
+#[test]
+fn disconnected_after_release() {
+    let rb = LocalRb::<Static<i32, 4>>::default();
+    let mut prod = unsafe { Prod::new(&rb) };
+    assert!(!prod.is_disconnected());
+    assert_eq!(prod.try_push_slice(&[0, 1]), Some(2));
+
+    let cons = unsafe { Cons::new(&rb) };
+    assert!(!prod.is_disconnected());
+    drop(cons);
+    assert!(prod.is_disconnected());
+    assert_eq!(prod.try_push_slice(&[2, 3]), None);
+    assert_eq!(prod.occupied_len(), 2);
+
+    let cons = unsafe { Cons::new(&rb) };
+    assert!(!prod.is_disconnected());
+    assert_eq!(prod.try_push_slice(&[2, 3, 4]), Some(2));
+    assert!(cons.iter().eq(&[0, 1, 2, 3]));
+}
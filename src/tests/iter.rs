@@ -56,6 +56,23 @@ fn pop_iter() {
     assert!(prod.is_empty());
 }
 
+#[test]
+fn try_iter() {
+    let mut rb = Rb::<Static<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(cons.try_iter().next(), None);
+
+    assert_eq!(prod.push_iter(0..3), 3);
+    let mut iter = cons.try_iter();
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(prod.push_iter(3..4), 1);
+    assert!(iter.eq(1..3));
+
+    assert!(cons.try_iter().eq(3..4));
+    assert!(cons.is_empty());
+}
+
 #[test]
 fn push_pop_iter_partial() {
     let mut rb = Rb::<Static<i32, 4>>::default();
@@ -77,3 +94,234 @@ fn push_pop_iter_partial() {
     assert_eq!(cons.try_pop().unwrap(), 5);
     assert!(prod.is_empty());
 }
+
+#[test]
+fn push_iter_owned() {
+    let mut rb = Rb::<Static<i32, 3>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    let (count, iter) = prod.push_iter_owned(0..5);
+    assert_eq!(count, 3);
+    assert!(cons.pop_iter().eq(0..3));
+
+    let (count, mut iter) = prod.push_iter_owned(iter);
+    assert_eq!(count, 2);
+    assert_eq!(iter.next(), None);
+    assert!(cons.pop_iter().eq(3..5));
+}
+
+#[test]
+fn refill_with() {
+    let mut rb = Rb::<Static<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    let mut calls = 0;
+    let mut counter = || {
+        calls += 1;
+        Some(calls)
+    };
+    assert_eq!(prod.refill_with(&mut counter), 4);
+    assert_eq!(prod.refill_with(&mut counter), 0);
+    assert!(cons.pop_iter().eq(1..=4));
+    assert_eq!(calls, 4);
+
+    let mut items = [5, 6].into_iter();
+    assert_eq!(prod.refill_with(|| items.next()), 2);
+    assert!(cons.pop_iter().eq([5, 6]));
+}
+
+#[test]
+fn push_iter_while() {
+    let mut rb = Rb::<Static<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    let mut iter = [1, 2, 10, 3, 4, 5].into_iter().peekable();
+    assert_eq!(prod.push_iter_while(&mut iter, |x| *x < 10), 2);
+    assert_eq!(iter.peek(), Some(&10));
+    assert!(cons.iter().copied().eq([1, 2]));
+
+    assert_eq!(prod.push_iter_while(&mut iter, |_| true), 2);
+    assert_eq!(iter.next(), Some(4));
+    assert!(cons.pop_iter().eq([1, 2, 10, 3]));
+}
+
+#[test]
+fn drain_filter() {
+    let mut rb = Rb::<Static<i32, 8>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_iter(0..5), 5);
+    assert_eq!(cons.skip(5), 5);
+    assert_eq!(prod.push_iter(0..8), 8);
+
+    assert!(cons.drain_filter(|x| x % 2 == 1).eq([1, 3, 5, 7]));
+    assert!(cons.iter().copied().eq([0, 2, 4, 6]));
+    assert_eq!(prod.vacant_len(), 4);
+
+    assert_eq!(prod.push_iter(8..12), 4);
+    assert!(cons.iter().copied().eq([0, 2, 4, 6, 8, 9, 10, 11]));
+}
+
+#[test]
+fn drain_filter_partial() {
+    let mut rb = Rb::<Static<i32, 8>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_iter(0..6), 6);
+    assert_eq!(cons.skip(6), 6);
+    assert_eq!(prod.push_iter(0..8), 8);
+
+    assert_eq!(cons.drain_filter(|x| x % 3 == 0).next(), Some(0));
+    assert!(cons.iter().copied().eq(1..8));
+
+    let mut iter = cons.drain_filter(|x| x % 3 == 0);
+    assert_eq!(iter.next(), Some(3));
+    assert_eq!(iter.next(), Some(6));
+    drop(iter);
+    assert!(cons.iter().copied().eq([1, 2, 4, 5, 7]));
+    assert_eq!(prod.vacant_len(), 3);
+}
+
+#[test]
+fn enumerate_iter() {
+    let mut rb = Rb::<Static<i32, 5>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(cons.enumerate_iter().next(), None);
+
+    assert_eq!(prod.push_iter(0..4), 4);
+    assert_eq!(cons.skip(3), 3);
+    assert_eq!(prod.push_iter(4..8), 4);
+    assert!(!cons.is_contiguous());
+
+    let mut len = 0;
+    for (i, x) in cons.enumerate_iter() {
+        assert_eq!(i, len);
+        assert_eq!(*x, i as i32 + 3);
+        len += 1;
+    }
+    assert_eq!(len, cons.occupied_len());
+}
+
+#[test]
+fn fold() {
+    let mut rb = Rb::<Static<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(cons.sum::<i32>(), 0);
+    assert_eq!(cons.product::<i32>(), 1);
+
+    assert_eq!(prod.push_iter(0..3), 3);
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(prod.push_iter(3..6), 3);
+    assert!(!cons.is_contiguous());
+
+    assert_eq!(cons.sum::<i32>(), 2 + 3 + 4 + 5);
+    assert_eq!(cons.product::<i32>(), 2 * 3 * 4 * 5);
+    assert_eq!(cons.fold(0, |acc, x| 10 * acc + x), 2345);
+    assert_eq!(cons.occupied_len(), 4);
+}
+
+#[test]
+fn copied() {
+    let mut rb = Rb::<Static<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_iter(0..3), 3);
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(prod.push_iter(3..6), 3);
+    assert!(!cons.is_contiguous());
+
+    assert_eq!(cons.copied().sum::<i32>(), 2 + 3 + 4 + 5);
+    assert!(cons.copied().eq(2..6));
+    assert_eq!(cons.occupied_len(), 4);
+}
+
+#[test]
+fn drain_each() {
+    use core::ops::ControlFlow;
+
+    let mut rb = Rb::<Static<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_iter(0..3), 3);
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(prod.push_iter(3..6), 3);
+
+    let mut sum = 0;
+    let count = cons.drain_each(|x| {
+        sum += x;
+        if x == 3 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+    assert_eq!((count, sum), (2, 2 + 3));
+    assert!(cons.copied().eq(4..6));
+
+    assert_eq!(cons.drain_each(|_| ControlFlow::Continue(())), 2);
+    assert!(cons.is_empty());
+    assert_eq!(cons.drain_each(|_| ControlFlow::Continue(())), 0);
+}
+
+/// Iterator which reports exact length `hint` regardless of actual one.
+struct LyingIter {
+    items: core::ops::Range<i32>,
+    hint: usize,
+}
+
+impl Iterator for LyingIter {
+    type Item = i32;
+    fn next(&mut self) -> Option<i32> {
+        self.items.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.hint, Some(self.hint))
+    }
+}
+
+#[test]
+fn push_iter_size_hint() {
+    let mut rb = Rb::<Static<i32, 6>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_iter(0..4), 4);
+    assert_eq!(cons.skip(4), 4);
+    // Exact hint, items wrap around.
+    let mut iter = 0..10;
+    assert_eq!(prod.push_iter(&mut iter), 6);
+    assert_eq!(iter.start, 6);
+    assert!(cons.pop_iter().eq(0..6));
+
+    // Iterator yields less items than hinted.
+    let mut iter = LyingIter { items: 0..3, hint: 5 };
+    assert_eq!(prod.push_iter(&mut iter), 3);
+    assert!(cons.pop_iter().eq(0..3));
+
+    // Iterator yields more items than hinted.
+    let mut iter = LyingIter { items: 0..10, hint: 3 };
+    assert_eq!(prod.push_iter(&mut iter), 6);
+    assert_eq!(iter.next(), Some(6));
+    assert!(cons.pop_iter().eq(0..6));
+
+    assert_eq!(prod.push_iter(LyingIter { items: 0..2, hint: 0 }), 2);
+    assert!(cons.pop_iter().eq(0..2));
+}
+
+#[test]
+fn map_items() {
+    let mut rb = Rb::<Static<u8, 4>>::default();
+    let (mut prod, cons) = rb.split_ref();
+    let mut cons = cons.map_items(char::from);
+
+    assert_eq!(prod.push_slice(b"abc"), 3);
+    assert_eq!(cons.try_pop(), Some('a'));
+    assert_eq!(cons.occupied_len(), 2);
+    assert_eq!(prod.push_slice(b"de"), 2);
+    assert!(cons.try_iter().take(3).eq("bcd".chars()));
+    assert_eq!(cons.occupied_len(), 1);
+
+    let mut cons = cons.into_inner();
+    assert_eq!(cons.try_pop(), Some(b'e'));
+    assert!(cons.is_empty());
+    assert_eq!(prod.vacant_len(), 4);
+}
@@ -0,0 +1,27 @@
+use crate::{storage::Static, traits::metrics::RbMetrics, traits::*, LocalRb, SharedRb};
+use alloc::{boxed::Box, vec, vec::Vec};
+
+#[test]
+fn dyn_metrics() {
+    let mut a = LocalRb::<Static<u8, 4>>::default();
+    a.push_slice(&[1, 2, 3]);
+    let mut b = SharedRb::<Static<i64, 2>>::default();
+    b.try_push(-1).unwrap();
+    let c = SharedRb::<Static<(), 3>>::default();
+
+    let registry: Vec<Box<dyn RbMetrics>> = vec![Box::new(a), Box::new(b), Box::new(c)];
+    let metrics: Vec<_> = registry.iter().map(|m| (m.capacity(), m.len(), m.vacant(), m.is_empty())).collect();
+    assert_eq!(metrics, [(4, 3, 1, false), (2, 1, 1, false), (3, 0, 3, true)]);
+}
+
+#[test]
+fn halves() {
+    let mut rb = SharedRb::<Static<i32, 3>>::default();
+    let (mut prod, cons) = rb.split_ref();
+    prod.try_push(0).unwrap();
+
+    let metrics: [&dyn RbMetrics; 2] = [&prod, &cons];
+    for m in metrics {
+        assert_eq!((m.capacity(), m.len(), m.vacant()), (3, 1, 2));
+    }
+}
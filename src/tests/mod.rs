@@ -4,17 +4,45 @@ use crate::LocalRb as Rb;
 use crate::SharedRb as Rb;
 
 mod access;
+#[cfg(feature = "allocator_api")]
+mod allocator;
 mod basic;
+#[cfg(feature = "std")]
+mod double;
 #[cfg(feature = "alloc")]
 mod drop;
 mod fmt_write;
 mod frozen;
+#[cfg(feature = "alloc")]
+mod heap;
+mod hold;
 mod iter;
+#[cfg(feature = "alloc")]
+mod metrics;
+#[cfg(feature = "std")]
+mod mpsc;
 mod overwrite;
+#[cfg(all(feature = "rayon", feature = "alloc"))]
+mod par_iter;
+#[cfg(feature = "bytemuck")]
+mod pod;
+mod policy;
+#[cfg(feature = "std")]
+mod rate;
+#[cfg(feature = "alloc")]
+mod rc;
 #[cfg(feature = "std")]
 mod read_write;
+mod search;
 #[cfg(feature = "std")]
 mod shared;
 #[cfg(feature = "alloc")]
 mod skip;
 mod slice;
+#[cfg(feature = "stats")]
+mod stats;
+#[cfg(feature = "alloc")]
+mod stream;
+#[cfg(all(feature = "trace", feature = "std"))]
+mod trace;
+mod transfer;
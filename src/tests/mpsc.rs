@@ -0,0 +1,68 @@
+use crate::{rb::MpscRb, traits::*};
+use std::{thread, vec::Vec};
+
+#[test]
+fn push_pop() {
+    let (prod, mut cons) = MpscRb::<i32>::new(4).split();
+    let other = prod.clone();
+
+    assert_eq!(prod.try_push(0), Ok(()));
+    assert_eq!(other.push_slice(&[1, 2, 3, 4]), 3);
+    assert_eq!(prod.try_push(5), Err(5));
+    assert!(cons.is_full());
+
+    assert_eq!(cons.try_pop(), Some(0));
+    assert_eq!(cons.try_pop(), Some(1));
+    assert_eq!(other.push_slice(&[4, 5, 6]), 2);
+    assert!(cons.pop_iter().eq(2..6));
+    assert!(cons.is_empty());
+}
+
+#[test]
+fn multiple_producers() {
+    const THREADS: usize = 4;
+    const COUNT: usize = 1000;
+
+    let (prod, mut cons) = MpscRb::<usize>::new(16).split();
+    let handles = (0..THREADS)
+        .map(|i| {
+            let prod = prod.clone();
+            thread::spawn(move || {
+                let items = (0..COUNT).map(|j| i * COUNT + j).collect::<Vec<_>>();
+                let mut pushed = 0;
+                while pushed < COUNT {
+                    if pushed % 2 == 0 {
+                        if prod.try_push(items[pushed]).is_ok() {
+                            pushed += 1;
+                        }
+                    } else {
+                        pushed += prod.push_slice(&items[pushed..usize::min(pushed + 3, COUNT)]);
+                    }
+                    thread::yield_now();
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    drop(prod);
+
+    let mut items = Vec::new();
+    while items.len() < THREADS * COUNT {
+        match cons.try_pop() {
+            Some(item) => items.push(item),
+            None => thread::yield_now(),
+        }
+    }
+    handles.into_iter().for_each(|h| h.join().unwrap());
+
+    assert!(cons.is_empty());
+    // Items of each producer keep their order.
+    for i in 0..THREADS {
+        assert!(items
+            .iter()
+            .copied()
+            .filter(|item| item / COUNT == i)
+            .eq((i * COUNT)..((i + 1) * COUNT)));
+    }
+    items.sort();
+    assert!(items.into_iter().eq(0..(THREADS * COUNT)));
+}
@@ -0,0 +1,20 @@
+use super::Rb;
+use crate::{storage::Heap, traits::*};
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+#[test]
+fn sum() {
+    const CAP: usize = 100_000;
+    let mut rb = Rb::<Heap<u64>>::new(CAP);
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_iter(0..CAP as u64), CAP);
+    cons.skip(CAP / 3);
+    assert_eq!(prod.push_iter(CAP as u64..), CAP / 3);
+    assert!(!cons.as_slices().1.is_empty());
+
+    assert_eq!(cons.par_iter().len(), CAP);
+    assert_eq!(cons.par_iter().sum::<u64>(), cons.iter().sum::<u64>());
+    assert_eq!(cons.par_iter().copied().max(), Some((CAP + CAP / 3 - 1) as u64));
+    assert_eq!(cons.occupied_len(), CAP);
+}
@@ -0,0 +1,34 @@
+use super::Rb;
+use crate::{storage::Static, traits::*};
+
+#[test]
+fn as_byte_slices() {
+    let mut rb = Rb::<Static<u32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_slice(&[0, 0, 0]), 3);
+    assert_eq!(cons.skip(3), 3);
+    assert_eq!(prod.push_slice(&[0x04030201, 0x08070605]), 2);
+
+    let (left, right) = cons.as_byte_slices();
+    assert_eq!(left, 0x04030201u32.to_ne_bytes());
+    assert_eq!(right, 0x08070605u32.to_ne_bytes());
+}
+
+#[test]
+fn push_bytes() {
+    let mut rb = Rb::<Static<u32, 4>>::default();
+    let (mut prod, cons) = rb.split_ref();
+
+    let mut bytes = [0u8; 14];
+    for (i, x) in [1u32, 2, 3].into_iter().enumerate() {
+        bytes[(4 * i + 1)..(4 * i + 5)].copy_from_slice(&x.to_ne_bytes());
+    }
+    bytes[13] = 0xff;
+
+    assert_eq!(prod.push_bytes(&bytes[1..]), 3);
+    assert!(cons.iter().copied().eq([1, 2, 3]));
+
+    assert_eq!(prod.push_bytes(&bytes[1..]), 1);
+    assert!(cons.iter().copied().eq([1, 2, 3, 1]));
+}
@@ -0,0 +1,50 @@
+use super::Rb;
+use crate::{rb::Policy, storage::Static, traits::*, utils::uninit_array};
+
+fn fill<P: Producer<Item = i32>>(prod: &mut P) -> (Result<(), i32>, usize, usize) {
+    let a = prod.try_push(0);
+    let b = prod.push_iter(1..4);
+    let c = prod.push_slice(&[4, 5]);
+    (a, b, c)
+}
+
+#[test]
+fn reject() {
+    let mut rb = Rb::<Static<i32, 4>>::with_policy(uninit_array(), Policy::Reject);
+    assert_eq!(rb.policy(), Policy::Reject);
+    assert_eq!(fill(&mut rb), (Ok(()), 3, 0));
+    assert_eq!(rb.try_push(6), Err(6));
+    assert!(rb.iter().copied().eq(0..4));
+}
+
+#[test]
+fn overwrite() {
+    let mut rb = Rb::<Static<i32, 4>>::with_policy(uninit_array(), Policy::Overwrite);
+    assert_eq!(rb.policy(), Policy::Overwrite);
+    assert_eq!(fill(&mut rb), (Ok(()), 3, 2));
+    assert_eq!(rb.try_push(6), Ok(()));
+    assert!(rb.iter().copied().eq(3..7));
+
+    assert_eq!(rb.push_iter(7..13), 6);
+    assert!(rb.iter().copied().eq(9..13));
+    assert_eq!(rb.push_slice(&[13, 14, 15, 16, 17]), 5);
+    assert!(rb.iter().copied().eq(14..18));
+}
+
+#[test]
+fn overwrite_push_iter_count() {
+    let mut rb = Rb::<Static<i32, 4>>::with_policy(uninit_array(), Policy::Overwrite);
+    let mut iter = 0..10;
+    assert_eq!(rb.push_iter(&mut iter), 10);
+    assert!(iter.is_empty());
+    assert_eq!(rb.occupied_len(), 4);
+    assert!(rb.iter().copied().eq(6..10));
+}
+
+#[test]
+fn overwrite_split() {
+    let mut rb = Rb::<Static<i32, 4>>::with_policy(uninit_array(), Policy::Overwrite);
+    let (mut prod, cons) = rb.split_ref();
+    assert_eq!(fill(&mut prod), (Ok(()), 3, 0));
+    assert!(cons.iter().copied().eq(0..4));
+}
@@ -0,0 +1,88 @@
+use crate::{
+    rb::{traits::ToRbRef, RateTrackedRb},
+    storage::Heap,
+    traits::*,
+    SharedRb,
+};
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+#[test]
+fn total() {
+    let mut rb = RateTrackedRb::new(SharedRb::<Heap<i32>>::new(4), Duration::from_secs(1), 2);
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_slice(&[0, 1, 2]), 3);
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(prod.push_iter(3..6), 3);
+    assert_eq!(cons.clear(), 4);
+
+    drop((prod, cons));
+    assert_eq!(rb.total_written(), 6);
+}
+
+#[test]
+fn items_per_sec() {
+    const PERIOD: Duration = Duration::from_millis(5);
+    const BATCH: usize = 5;
+    // Nominal rate of the second phase.
+    const RATE: f64 = BATCH as f64 / 0.005;
+
+    let (mut prod, mut cons) = RateTrackedRb::new(SharedRb::<Heap<i32>>::new(2048), Duration::from_millis(50), 1).split();
+
+    // Burst that must fall out of the window.
+    assert_eq!(prod.push_iter(0..1000), 1000);
+    for _ in 0..20 {
+        thread::sleep(PERIOD);
+        assert_eq!(prod.push_iter(0..BATCH as i32), BATCH);
+        cons.clear();
+    }
+
+    let rate = prod.rb().items_per_sec();
+    assert!(rate > 0.2 * RATE && rate < 1.5 * RATE, "rate: {}", rate);
+    assert_eq!(prod.rb().total_written(), 1000 + 20 * BATCH);
+}
+
+#[test]
+fn is_stalled() {
+    const TICK: Duration = Duration::from_millis(1);
+
+    let mut rb = RateTrackedRb::new(SharedRb::<Heap<i32>>::new(4), Duration::from_secs(1), 1);
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(prod.push_iter(0..4), 4);
+
+    let since = Instant::now();
+    thread::sleep(TICK);
+    assert!(cons.rb().is_stalled(since, 1));
+    assert!(!cons.rb().is_stalled(since, 0));
+
+    assert_eq!(cons.skip(2), 2);
+    assert!(!cons.rb().is_stalled(since, 2));
+    assert!(cons.rb().is_stalled(since, 3));
+
+    thread::sleep(TICK);
+    let since = Instant::now();
+    thread::sleep(TICK);
+    assert!(cons.rb().is_stalled(since, 1));
+
+    assert_eq!(cons.try_pop(), Some(2));
+    assert!(!cons.rb().is_stalled(since, 1));
+    assert_eq!(cons.rb().total_read(), 3);
+}
+
+#[test]
+fn overwrite_is_stalled() {
+    let mut rb = RateTrackedRb::new(SharedRb::<Heap<i32>>::new(4), Duration::from_secs(1), 1);
+    let since = Instant::now();
+    rb.push_slice_overwrite(&[0, 1, 2, 3, 4, 5]);
+    for i in 6..10 {
+        assert_eq!(rb.push_overwrite(i), Some(i - 4));
+    }
+    assert!(rb.make_contiguous().iter().eq(&[6, 7, 8, 9]));
+
+    assert_eq!(rb.total_written(), 8);
+    assert_eq!(rb.total_read(), 0);
+    assert!(rb.is_stalled(since, 1));
+}
@@ -0,0 +1,20 @@
+use crate::{rb::traits::ToRbRef, storage::Heap, traits::*, LocalRb};
+use alloc::rc::Rc;
+
+#[test]
+fn split_rc() {
+    let (mut prod, mut cons) = LocalRb::<Heap<i32>>::new(2).split_rc();
+    let rb = prod.rb_ref().clone();
+    assert!(Rc::ptr_eq(&rb, cons.rb_ref()));
+    assert_eq!(Rc::strong_count(&rb), 3);
+
+    assert_eq!(prod.try_push(1), Ok(()));
+    assert_eq!(rb.len(), 1);
+    assert_eq!(cons.occupied_len(), 1);
+
+    assert_eq!(cons.try_pop(), Some(1));
+    assert!(rb.is_empty());
+
+    drop((prod, cons));
+    assert_eq!(Rc::strong_count(&rb), 1);
+}
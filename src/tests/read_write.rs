@@ -1,5 +1,5 @@
 use super::Rb;
-use crate::{storage::Static, traits::*};
+use crate::{halves::FrozenProd, storage::Static, traits::*};
 use std::io;
 
 #[test]
@@ -101,3 +101,212 @@ fn count() {
     assert_eq!(cons1.pop_slice(&mut tmp), 4);
     assert_eq!(tmp[0..4], [3, 4, 5, 6]);
 }
+
+#[test]
+fn disconnected() {
+    use std::io::Write;
+
+    let mut rb0 = Rb::<Static<u8, 4>>::default();
+    let mut rb1 = Rb::<Static<u8, 4>>::default();
+    let (mut prod0, mut cons0) = rb0.split_ref();
+    let (mut prod1, cons1) = rb1.split_ref();
+
+    assert_eq!(prod0.push_slice(&[0, 1, 2, 3]), 4);
+    assert!(!prod1.is_disconnected());
+    assert_eq!(prod1.read_from(&mut cons0, Some(2)).unwrap(), 2);
+    assert_eq!(prod1.write(&[4, 5, 6]).unwrap(), 2);
+    assert_eq!(prod1.write(&[6]).unwrap_err().kind(), io::ErrorKind::WouldBlock);
+
+    drop(cons1);
+    assert!(prod1.is_disconnected());
+    assert_eq!(prod1.read_from(&mut cons0, None).unwrap_err().kind(), io::ErrorKind::BrokenPipe);
+    assert_eq!(prod1.write(&[6]).unwrap_err().kind(), io::ErrorKind::BrokenPipe);
+    assert_eq!(cons0.occupied_len(), 2);
+}
+
+#[test]
+fn read_adapter() {
+    use crate::consumer::ReadAdapter;
+    use std::io::Read;
+
+    struct Frame {
+        bytes: [u8; 3],
+    }
+
+    let mut rb = Rb::<Static<Frame, 4>>::default();
+    let (mut prod, cons) = rb.split_ref();
+    for i in 0..3 {
+        let bytes = [i, i + 10, i + 20];
+        assert!(prod.try_push(Frame { bytes }).is_ok());
+    }
+
+    let mut reader = ReadAdapter::new(cons, |frame: &Frame| &frame.bytes);
+    let mut buffer = [0; 2];
+    assert_eq!(reader.read(&mut buffer).unwrap(), 2);
+    assert_eq!(buffer, [0, 10]);
+    assert_eq!(reader.read(&mut buffer).unwrap(), 2);
+    assert_eq!(buffer, [20, 1]);
+    assert_eq!(reader.read(&mut buffer).unwrap(), 2);
+    assert_eq!(buffer, [11, 21]);
+
+    let mut buffer = [0; 8];
+    assert_eq!(reader.read(&mut buffer).unwrap(), 3);
+    assert_eq!(buffer[..3], [2, 12, 22]);
+    assert_eq!(reader.read(&mut buffer).unwrap_err().kind(), io::ErrorKind::WouldBlock);
+
+    assert!(prod.try_push(Frame { bytes: [3, 13, 23] }).is_ok());
+    assert_eq!(reader.read(&mut buffer).unwrap(), 3);
+    assert_eq!(buffer[..3], [3, 13, 23]);
+    assert!(reader.into_inner().is_empty());
+}
+
+#[test]
+fn flush() {
+    use std::io::Write;
+
+    let rb = Rb::<Static<u8, 4>>::default();
+    let (mut prod, cons) = unsafe { (FrozenProd::new(&rb), <Rb<_> as SplitRef>::RefCons::new(&rb)) };
+
+    assert_eq!(prod.write(&[1, 2, 3]).unwrap(), 3);
+    assert!(cons.is_empty());
+    prod.flush().unwrap();
+    assert_eq!(cons.as_slices().0, [1, 2, 3]);
+}
+
+/// Reader that fills all provided buffers in a single `read_vectored` call and counts the calls.
+struct VectoredReader {
+    data: std::vec::Vec<u8>,
+    calls: usize,
+}
+
+impl io::Read for VectoredReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.read_vectored(&mut [io::IoSliceMut::new(buf)])
+    }
+    fn read_vectored(&mut self, bufs: &mut [io::IoSliceMut<'_>]) -> io::Result<usize> {
+        self.calls += 1;
+        let mut count = 0;
+        for buf in bufs {
+            let n = usize::min(buf.len(), self.data.len());
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data.drain(..n);
+            count += n;
+        }
+        Ok(count)
+    }
+}
+
+#[test]
+fn read_from_vectored() {
+    let mut rb = Rb::<Static<u8, 6>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_slice(&[0; 4]), 4);
+    assert_eq!(cons.skip(4), 4);
+    assert_eq!(prod.vacant_layout(), (2, 4));
+
+    let mut reader = VectoredReader {
+        data: (1..=10).collect(),
+        calls: 0,
+    };
+    assert_eq!(prod.read_from_vectored(&mut reader, Some(5)).unwrap(), 5);
+    assert_eq!(reader.calls, 1);
+    assert_eq!(cons.as_slices(), (&[1, 2][..], &[3, 4, 5][..]));
+
+    assert_eq!(prod.read_from_vectored(&mut reader, None).unwrap(), 1);
+    assert_eq!(prod.read_from_vectored(&mut reader, None).unwrap(), 0);
+    assert_eq!(reader.calls, 3);
+    assert!(cons.iter().copied().eq(1..=6));
+}
+
+/// Writer that accepts at most `limit` bytes from all provided buffers in a single `write_vectored` call and counts the calls.
+struct VectoredWriter {
+    data: std::vec::Vec<u8>,
+    limit: usize,
+    calls: usize,
+}
+
+impl io::Write for VectoredWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_vectored(&[io::IoSlice::new(buf)])
+    }
+    fn write_vectored(&mut self, bufs: &[io::IoSlice<'_>]) -> io::Result<usize> {
+        self.calls += 1;
+        let mut count = 0;
+        for buf in bufs {
+            let n = usize::min(buf.len(), self.limit - count);
+            self.data.extend_from_slice(&buf[..n]);
+            count += n;
+        }
+        Ok(count)
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn write_into_vectored() {
+    let mut rb = Rb::<Static<u8, 6>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_slice(&[0; 4]), 4);
+    assert_eq!(cons.skip(4), 4);
+    assert_eq!(prod.push_slice(&[1, 2, 3, 4, 5, 6]), 6);
+    assert_eq!(cons.occupied_layout(), (2, 4));
+
+    let mut writer = VectoredWriter {
+        data: std::vec::Vec::new(),
+        limit: 5,
+        calls: 0,
+    };
+    assert_eq!(cons.write_into_vectored(&mut writer, None).unwrap(), 5);
+    assert_eq!(writer.calls, 1);
+    assert_eq!(writer.data, [1, 2, 3, 4, 5]);
+    assert_eq!(cons.occupied_len(), 1);
+
+    assert_eq!(cons.write_into_vectored(&mut writer, None).unwrap(), 1);
+    assert_eq!(cons.write_into_vectored(&mut writer, None).unwrap(), 0);
+    assert_eq!(writer.data, [1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn seekable_cons() {
+    use crate::consumer::SeekableCons;
+    use std::io::{Read, Seek, SeekFrom};
+
+    let mut rb = Rb::<Static<u8, 8>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(prod.push_slice(&[0; 5]), 5);
+    assert_eq!(cons.skip(5), 5);
+    assert_eq!(prod.push_slice(&[0, 1, 2, 3, 4, 5, 6]), 7);
+    assert!(!cons.is_contiguous());
+
+    let mut reader = SeekableCons::new(cons, 4);
+    let mut buffer = [0; 3];
+    assert_eq!(reader.read(&mut buffer).unwrap(), 3);
+    assert_eq!(buffer, [0, 1, 2]);
+    assert_eq!(reader.seek(SeekFrom::Current(-2)).unwrap(), 1);
+    assert_eq!(reader.read(&mut buffer).unwrap(), 3);
+    assert_eq!(buffer, [1, 2, 3]);
+
+    assert_eq!(reader.seek(SeekFrom::End(-1)).unwrap(), 6);
+    assert_eq!(reader.read(&mut buffer).unwrap(), 1);
+    assert_eq!(buffer[0], 6);
+    // Bytes behind the last `keep` ones are committed.
+    assert_eq!(reader.cursor(), 4);
+    assert_eq!(reader.seek(SeekFrom::Start(0)).unwrap(), 0);
+    assert_eq!(reader.read(&mut buffer).unwrap(), 3);
+    assert_eq!(buffer, [3, 4, 5]);
+
+    assert_eq!(reader.seek(SeekFrom::Current(2)).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    assert_eq!(reader.seek(SeekFrom::Current(-4)).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    assert_eq!(reader.seek(SeekFrom::Start(5)).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    assert_eq!(reader.cursor(), 3);
+    assert_eq!(reader.seek(SeekFrom::Current(1)).unwrap(), 4);
+    assert_eq!(reader.read(&mut buffer).unwrap_err().kind(), io::ErrorKind::WouldBlock);
+
+    reader.commit();
+    assert_eq!(reader.cursor(), 0);
+    assert!(reader.into_inner().is_empty());
+}
@@ -0,0 +1,85 @@
+use super::Rb;
+use crate::{storage::Static, traits::*};
+
+#[test]
+fn partition_point() {
+    let mut rb = Rb::<Static<i32, 8>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_iter(0..5), 5);
+    assert_eq!(cons.skip(5), 5);
+    assert_eq!(prod.push_slice(&[1, 3, 3, 5, 7, 9, 11]), 7);
+    let (left, right) = cons.as_slices();
+    assert_eq!((left.len(), right.len()), (3, 4));
+
+    let vec = [1, 3, 3, 5, 7, 9, 11];
+    for x in 0..13 {
+        assert_eq!(cons.partition_point(|y| *y < x), vec.partition_point(|y| *y < x));
+    }
+}
+
+#[test]
+fn partition_point_empty() {
+    let mut rb = Rb::<Static<i32, 4>>::default();
+    let (_, cons) = rb.split_ref();
+
+    assert_eq!(cons.partition_point(|_| true), 0);
+}
+
+#[test]
+fn min_max() {
+    let mut rb = Rb::<Static<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(cons.max(), None);
+    assert_eq!(cons.min_by_key(|x| x.abs()), None);
+
+    assert_eq!(prod.push_slice(&[5, -7, 1]), 3);
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(prod.push_slice(&[-3, 9, 2]), 3);
+    assert_eq!(cons.as_slices(), (&[1, -3][..], &[9, 2][..]));
+
+    assert_eq!(cons.max(), Some(&9));
+    assert_eq!(cons.min(), Some(&-3));
+    assert_eq!(cons.max_by_key(|x| -x), Some(&-3));
+    assert_eq!(cons.min_by_key(|x| x.abs()), Some(&1));
+    assert_eq!(cons.occupied_len(), 4);
+}
+
+#[test]
+fn pop_run() {
+    let mut rb = Rb::<Static<i32, 6>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(cons.pop_run(), None);
+
+    assert_eq!(prod.push_iter(0..4), 4);
+    assert_eq!(cons.skip(4), 4);
+    assert_eq!(prod.push_slice(&[1, 2, 2, 2, 3, 3]), 6);
+    assert!(!cons.is_contiguous());
+
+    assert_eq!(cons.pop_run(), Some((1, 1)));
+    assert_eq!(cons.pop_run(), Some((2, 3)));
+    assert_eq!(prod.push_slice(&[3, 4]), 2);
+    assert_eq!(cons.pop_run(), Some((3, 3)));
+    assert_eq!(cons.pop_run(), Some((4, 1)));
+    assert_eq!(cons.pop_run(), None);
+    assert!(cons.is_empty());
+}
+
+#[test]
+fn peek_run_len() {
+    let mut rb = Rb::<Static<u8, 6>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(cons.peek_run_len(|_| true), 0);
+
+    assert_eq!(prod.push_slice(b"xxxx"), 4);
+    assert_eq!(cons.skip(4), 4);
+    assert_eq!(prod.push_slice(b"abc d"), 5);
+    assert!(!cons.is_contiguous());
+
+    assert_eq!(cons.peek_run_len(u8::is_ascii_alphabetic), 3);
+    assert_eq!(cons.peek_run_len(u8::is_ascii_digit), 0);
+    assert_eq!(cons.peek_run_len(|_| true), 5);
+    assert_eq!(cons.occupied_len(), 5);
+}
@@ -1,5 +1,13 @@
-use crate::{storage::Heap, traits::*, SharedRb};
-use std::{thread, thread::sleep, time::Duration, vec::Vec};
+use crate::{storage::Heap, traits::*, CachingProd, SharedRb};
+use std::{
+    boxed::Box,
+    sync::Arc,
+    thread,
+    thread::sleep,
+    time::{Duration, Instant},
+    vec,
+    vec::Vec,
+};
 
 #[cfg(feature = "std")]
 #[test]
@@ -38,6 +46,7 @@ fn concurrent() {
 #[cfg(feature = "std")]
 #[test]
 #[ignore]
+#[allow(unused_assignments)]
 fn concurrent_spin_many() {
     const COUNT: usize = 10_000_000;
     let mut msg = (1..=u8::MAX).cycle().take(COUNT).chain([0]);
@@ -78,3 +87,223 @@ fn concurrent_spin_many() {
     pjh.join().unwrap();
     assert_eq!(cjh.join().unwrap(), COUNT);
 }
+
+#[test]
+fn send_halves() {
+    fn assert_send<T: Send>(_: &T) {}
+
+    let rb = SharedRb::<Heap<Vec<i32>>>::new(2);
+    let (mut prod, mut cons) = rb.split();
+    assert_send(&prod);
+    assert_send(&cons);
+
+    thread::spawn(move || prod.try_push(vec![1, 2, 3]).unwrap()).join().unwrap();
+    let cjh = thread::spawn(move || cons.try_pop().unwrap());
+    assert_eq!(cjh.join().unwrap(), [1, 2, 3]);
+}
+
+#[test]
+fn split_static() {
+    let rb: &'static mut SharedRb<Heap<i32>> = Box::leak(Box::new(SharedRb::new(4)));
+    let (mut prod, mut cons) = rb.split_static();
+
+    let pjh = thread::spawn(move || {
+        for i in 0..16 {
+            while prod.try_push(i).is_err() {
+                thread::yield_now();
+            }
+        }
+    });
+    let cjh = thread::spawn(move || {
+        for i in 0..16 {
+            loop {
+                if let Some(x) = cons.try_pop() {
+                    assert_eq!(x, i);
+                    break;
+                }
+                thread::yield_now();
+            }
+        }
+    });
+
+    pjh.join().unwrap();
+    cjh.join().unwrap();
+}
+
+#[test]
+fn move_halves() {
+    struct Owner {
+        prod: CachingProd<Arc<SharedRb<Heap<i32>>>>,
+    }
+
+    let (prod_a, mut cons_a) = SharedRb::<Heap<i32>>::new(2).split();
+    let (prod_b, mut cons_b) = SharedRb::<Heap<i32>>::new(2).split();
+    let mut owner_a = Owner { prod: prod_a };
+    let mut owner_b = Box::new(Owner { prod: prod_b });
+
+    owner_a.prod.try_push(0).unwrap();
+    owner_a.prod.try_push(1).unwrap();
+    assert_eq!(owner_a.prod.try_push(2), Err(2));
+    assert_eq!(cons_a.try_pop(), Some(0));
+
+    // Move halves between owners, cached indices must be moved along.
+    core::mem::swap(&mut owner_a, &mut owner_b);
+    let mut cons_a = Box::new(cons_a);
+
+    owner_b.prod.try_push(2).unwrap();
+    assert_eq!(owner_b.prod.try_push(3), Err(3));
+    owner_a.prod.try_push(10).unwrap();
+
+    assert!(cons_a.pop_iter().eq([1, 2]));
+    assert!(cons_b.pop_iter().eq([10]));
+    assert_eq!(owner_b.prod.vacant_len(), 2);
+}
+
+#[test]
+fn index_padding() {
+    use crate::storage::Static;
+    use core::{mem::size_of, sync::atomic::AtomicUsize};
+    use crossbeam_utils::CachePadded;
+
+    let size = size_of::<SharedRb<Static<u8, 1>>>();
+    let padded = 2 * size_of::<CachePadded<AtomicUsize>>();
+    if cfg!(feature = "compact") {
+        assert!(size < padded);
+    } else {
+        assert!(size >= padded);
+    }
+}
+
+#[test]
+fn observer_handle() {
+    use core::sync::atomic::{AtomicBool, Ordering};
+
+    const COUNT: usize = 10000;
+
+    let (mut prod, mut cons) = SharedRb::<Heap<usize>>::new(8).split();
+    let obs = cons.observer_handle();
+    assert!(obs.is_empty());
+
+    assert_eq!(prod.push_slice(&[0, 1, 2]), 3);
+    assert_eq!(obs.occupied_len(), 3);
+    assert_eq!(cons.try_pop(), Some(0));
+    assert_eq!(obs.clone().occupied_len(), 2);
+    assert_eq!(cons.skip(2), 2);
+
+    let pjh = thread::spawn(move || {
+        for i in 0..COUNT {
+            while prod.try_push(i).is_err() {
+                thread::yield_now();
+            }
+        }
+    });
+    let done = Arc::new(AtomicBool::new(false));
+    let ojh = thread::spawn({
+        let obs = obs.clone();
+        let done = done.clone();
+        move || {
+            while !done.load(Ordering::Acquire) {
+                assert!(obs.occupied_len() <= obs.capacity().get());
+                thread::yield_now();
+            }
+        }
+    });
+    let mut next = 0;
+    while next < COUNT {
+        match cons.try_pop() {
+            Some(item) => {
+                assert_eq!(item, next);
+                next += 1;
+            }
+            None => thread::yield_now(),
+        }
+    }
+    done.store(true, Ordering::Release);
+    pjh.join().unwrap();
+    ojh.join().unwrap();
+    assert_eq!(obs.occupied_len(), 0);
+}
+
+#[test]
+fn len_relaxed() {
+    const COUNT: usize = 100000;
+
+    let rb = SharedRb::<Heap<usize>>::new(16);
+    assert_eq!(rb.len_relaxed(), 0);
+    let (mut prod, mut cons) = rb.split();
+
+    let pjh = thread::spawn(move || {
+        for i in 0..COUNT {
+            while prod.try_push(i).is_err() {
+                thread::yield_now();
+            }
+        }
+    });
+    let mut next = 0;
+    while next < COUNT {
+        let len = cons.len_relaxed();
+        assert!(len <= cons.capacity().get());
+        // Items counted by the consumer are available to it.
+        for _ in 0..len {
+            assert_eq!(cons.try_pop(), Some(next));
+            next += 1;
+        }
+        if len == 0 {
+            thread::yield_now();
+        }
+    }
+    pjh.join().unwrap();
+    assert_eq!(cons.len_relaxed(), 0);
+}
+
+#[test]
+fn push_deadline() {
+    let rb = SharedRb::<Heap<i32>>::new(1);
+    let (mut prod, mut cons) = rb.split();
+
+    assert_eq!(prod.push_deadline(0, Instant::now()), Ok(()));
+    let deadline = Instant::now() + Duration::from_millis(10);
+    assert_eq!(prod.push_deadline(1, deadline), Err(1));
+    assert!(Instant::now() >= deadline);
+
+    let cjh = thread::spawn(move || {
+        sleep(Duration::from_millis(10));
+        assert_eq!(cons.try_pop(), Some(0));
+        cons
+    });
+    assert_eq!(prod.push_deadline(1, Instant::now() + Duration::from_secs(10)), Ok(()));
+    let mut cons = cjh.join().unwrap();
+    assert_eq!(cons.try_pop(), Some(1));
+}
+
+#[test]
+fn slices_under_concurrent_push() {
+    const COUNT: usize = 100000;
+
+    let (mut prod, mut cons) = SharedRb::<Heap<usize>>::new(64).split();
+
+    let pjh = thread::spawn(move || {
+        for i in 0..COUNT {
+            while prod.try_push(i).is_err() {
+                thread::yield_now();
+            }
+        }
+    });
+    let mut next = 0;
+    while next < COUNT {
+        let (left, right) = cons.as_slices();
+        let observed = left.iter().chain(right).copied().collect::<Vec<_>>();
+        assert!(observed.iter().copied().eq(next..(next + observed.len())));
+        // Let the producer fill vacant places while the slices are borrowed.
+        for _ in 0..4 {
+            thread::yield_now();
+            assert!(left.iter().chain(right).eq(observed.iter()));
+            // New items may appear after the observed ones but never replace them.
+            assert!(cons.iter().take(observed.len()).eq(observed.iter()));
+        }
+        let count = usize::max(observed.len() / 2, 1);
+        next += cons.skip(count);
+    }
+    pjh.join().unwrap();
+    assert!(cons.is_empty());
+}
@@ -1,6 +1,6 @@
 use super::Rb;
 use crate::{storage::Static, traits::*};
-use alloc::rc::Rc;
+use alloc::{rc::Rc, vec::Vec};
 
 #[test]
 fn skip() {
@@ -70,3 +70,47 @@ fn skip_drop() {
     // Check that items are dropped
     assert_eq!(Rc::strong_count(&rc), 1);
 }
+
+#[test]
+fn skip_all_or() {
+    let rc = Rc::<()>::new(());
+
+    const CAP: usize = 10;
+    let mut rb = Rb::<Static<(i32, Rc<()>), CAP>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_iter((0..6).map(|i| (i, rc.clone()))), 6);
+    assert_eq!(cons.skip(6), 6);
+    assert_eq!(prod.push_iter((0..CAP as i32).map(|i| (i, rc.clone()))), CAP);
+    assert!(!cons.is_contiguous());
+
+    assert_eq!(cons.skip_all_or(3), CAP - 3);
+    assert_eq!(Rc::strong_count(&rc), 3 + 1);
+    assert_eq!(cons.iter().map(|(i, _)| *i).collect::<Vec<_>>(), [7, 8, 9]);
+
+    assert_eq!(cons.skip_all_or(5), 0);
+    assert_eq!(cons.occupied_len(), 3);
+    assert_eq!(cons.skip_all_or(0), 3);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn decimate() {
+    let mut rb = Rb::<Static<i32, 12>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_iter(0..5), 5);
+    assert_eq!(cons.skip(5), 5);
+    assert_eq!(prod.push_iter(0..12), 12);
+
+    assert_eq!(cons.decimate(3), [2, 5, 8, 11]);
+    assert!(cons.is_empty());
+
+    assert_eq!(prod.push_iter(0..5), 5);
+    assert_eq!(cons.decimate(2), [1, 3]);
+    assert!(cons.is_empty());
+
+    assert_eq!(prod.push_iter(0..3), 3);
+    assert_eq!(cons.decimate(0), [0, 1, 2]);
+    assert_eq!(cons.decimate(4), []);
+}
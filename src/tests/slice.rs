@@ -95,3 +95,73 @@ fn move_slice_count() {
     assert_eq!(cons1.pop_slice(&mut tmp), 4);
     assert_eq!(tmp[0..4], [6, 7, 8, 9]);
 }
+
+#[test]
+fn rchunks() {
+    let mut rb = Rb::<Static<i32, 4>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(cons.rchunks().count(), 0);
+
+    assert_eq!(prod.push_slice(&[0, 1, 2]), 3);
+    assert!(cons.rchunks().eq([&[0, 1, 2][..]]));
+
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(prod.push_slice(&[3, 4, 5]), 3);
+    assert!(cons.rchunks().eq([&[4, 5][..], &[2, 3][..]]));
+}
+
+#[test]
+fn occupied_chunks_exact() {
+    let mut rb = Rb::<Static<i32, 8>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(cons.occupied_chunks_exact(2).count(), 0);
+
+    assert_eq!(prod.push_slice(&[0; 3]), 3);
+    assert_eq!(cons.skip(3), 3);
+    // First slice has 5 items and second one has 3.
+    assert_eq!(prod.push_slice(&[0, 1, 2, 3, 4, 5, 6, 7]), 8);
+    assert!(cons.occupied_chunks_exact(2).eq([&[0, 1][..], &[2, 3], &[5, 6]]));
+    assert!(cons.occupied_chunks_exact(3).eq([&[0, 1, 2][..], &[5, 6, 7]]));
+    assert!(cons.occupied_chunks_exact(4).eq([&[0, 1, 2, 3][..]]));
+    assert_eq!(cons.occupied_chunks_exact(6).count(), 0);
+}
+
+#[test]
+fn pop_deinterleave() {
+    let mut rb = Rb::<Static<i16, 5>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_slice(&[0, 0, 0]), 3);
+    assert_eq!(cons.skip(3), 3);
+    // Second frame straddles the wrap.
+    assert_eq!(prod.push_slice(&[1, -1, 2, -2, 3]), 5);
+
+    let (mut left, mut right) = ([0; 4], [0; 4]);
+    assert_eq!(cons.pop_deinterleave([&mut left, &mut right]), 2);
+    assert_eq!(left[..2], [1, 2]);
+    assert_eq!(right[..2], [-1, -2]);
+    assert_eq!(cons.occupied_len(), 1);
+
+    assert_eq!(prod.push_slice(&[-3, 4, -4]), 3);
+    assert_eq!(cons.pop_deinterleave([&mut left[..1], &mut right]), 1);
+    assert_eq!((left[0], right[0]), (3, -3));
+    assert!(cons.iter().copied().eq([4, -4]));
+}
+
+#[test]
+fn peek_array() {
+    let mut rb = Rb::<Static<i32, 6>>::default();
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_slice(&[0, 0, 0, 0]), 4);
+    assert_eq!(cons.skip(4), 4);
+    assert_eq!(prod.push_slice(&[1, 2, 3]), 3);
+    assert_eq!(cons.peek_array::<4>(), None);
+
+    assert_eq!(prod.push_slice(&[4, 5]), 2);
+    assert_eq!(cons.peek_array::<4>(), Some([1, 2, 3, 4]));
+    assert_eq!(cons.peek_array::<0>(), Some([]));
+    assert_eq!(cons.occupied_len(), 5);
+}
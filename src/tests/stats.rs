@@ -0,0 +1,24 @@
+use crate::{rb::traits::ToRbRef, storage::Static, traits::*, SharedRb};
+
+#[test]
+fn generation() {
+    let mut rb = SharedRb::<Static<i32, 4>>::default();
+    assert_eq!(rb.generation(), 0);
+    let (mut prod, mut cons) = rb.split_ref();
+
+    let gen = prod.rb().generation();
+    assert!(!prod.rb().changed_since(gen));
+
+    assert_eq!(prod.try_push(0), Ok(()));
+    assert_eq!(prod.try_push(1), Ok(()));
+    assert_eq!(prod.rb().generation(), gen + 2);
+    assert!(prod.rb().changed_since(gen));
+
+    let gen = cons.rb().generation();
+    assert_eq!(cons.try_pop(), Some(0));
+    assert_eq!(cons.skip(1), 1);
+    assert!(!cons.rb().changed_since(gen));
+
+    assert_eq!(prod.push_slice(&[2, 3, 4]), 3);
+    assert_eq!(cons.rb().generation(), gen + 1);
+}
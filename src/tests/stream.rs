@@ -0,0 +1,27 @@
+use crate::rb::StreamRb;
+
+#[test]
+fn close() {
+    let mut rb = StreamRb::<i32>::new(3);
+    let (mut prod, mut cons) = rb.split_ref();
+    assert_eq!(cons.pop(), None);
+
+    assert_eq!(prod.try_push(0), Ok(()));
+    assert_eq!(prod.try_push(1), Ok(()));
+    assert_eq!(prod.try_push(2), Err(2));
+    prod.close();
+    assert_eq!(cons.occupied_len(), 3);
+
+    assert_eq!(cons.pop(), Some(Some(0)));
+    assert_eq!(cons.pop(), Some(Some(1)));
+    assert_eq!(cons.pop(), Some(None));
+    assert_eq!(cons.pop(), None);
+}
+
+#[test]
+fn close_empty() {
+    let (prod, mut cons) = StreamRb::<i32>::new(2).split();
+    prod.close();
+    assert_eq!(cons.pop(), Some(None));
+    assert_eq!(cons.pop(), None);
+}
@@ -0,0 +1,60 @@
+use crate::{
+    rb::{
+        instrumented::{Event, EventLog, InstrumentedRb},
+        traits::ToRbRef,
+    },
+    storage::Static,
+    traits::*,
+    SharedRb,
+};
+use core::cell::Cell;
+
+#[test]
+fn callback() {
+    let (reads, writes) = (Cell::new(0), Cell::new(0));
+    let mut rb = InstrumentedRb::new(SharedRb::<Static<i32, 4>>::default(), |event| match event {
+        Event::Read(n) => reads.set(reads.get() + n),
+        Event::Write(n) => writes.set(writes.get() + n),
+    });
+    let (mut prod, mut cons) = rb.split_ref();
+
+    assert_eq!(prod.push_slice(&[0, 1, 2]), 3);
+    assert_eq!(prod.try_push(3), Ok(()));
+    assert_eq!(prod.try_push(4), Err(4));
+    assert_eq!((reads.get(), writes.get()), (0, 4));
+
+    assert_eq!(cons.try_pop(), Some(0));
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!((reads.get(), writes.get()), (3, 4));
+
+    assert_eq!(prod.push_iter(4..7), 3);
+    assert_eq!(cons.clear(), 4);
+    assert_eq!((reads.get(), writes.get()), (7, 7));
+}
+
+#[test]
+fn log() {
+    let rb = InstrumentedRb::new(SharedRb::<Static<i32, 4>>::default(), EventLog::<3>::default());
+    let (mut prod, mut cons) = rb.split();
+
+    prod.try_push(0).unwrap();
+    assert_eq!(prod.push_slice(&[1, 2, 3]), 3);
+    assert_eq!(cons.skip(2), 2);
+    assert_eq!(cons.try_pop(), Some(2));
+
+    assert_eq!(cons.rb().tracer().events(), [Event::Write(3), Event::Read(2), Event::Read(1)]);
+}
+
+#[test]
+fn overwrite() {
+    let mut rb = InstrumentedRb::new(SharedRb::<Static<i32, 2>>::default(), EventLog::<4>::default());
+
+    assert_eq!(rb.push_overwrite(0), None);
+    assert_eq!(rb.push_overwrite(1), None);
+    assert_eq!(rb.push_overwrite(2), Some(0));
+
+    assert_eq!(
+        rb.tracer().events(),
+        [Event::Write(1), Event::Write(1), Event::Read(1), Event::Write(1)]
+    );
+}
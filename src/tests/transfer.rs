@@ -0,0 +1,64 @@
+use super::Rb;
+use crate::{storage::Static, traits::*};
+
+#[test]
+fn splice_contiguous() {
+    let mut src = Rb::<Static<i32, 8>>::default();
+    let mut dst = Rb::<Static<i32, 8>>::default();
+    let (mut src_prod, mut src_cons) = src.split_ref();
+    let (mut dst_prod, dst_cons) = dst.split_ref();
+
+    assert_eq!(src_prod.push_iter(0..6), 6);
+    assert_eq!(dst_prod.push_iter(0..2), 2);
+
+    assert_eq!(src_cons.splice_into(&mut dst_prod, 4), 4);
+    assert!(src_cons.iter().copied().eq(4..6));
+    assert!(dst_cons.iter().copied().eq((0..2).chain(0..4)));
+
+    assert_eq!(src_cons.splice_into(&mut dst_prod, 4), 2);
+    assert!(src_cons.is_empty());
+    assert!(dst_cons.iter().copied().eq((0..2).chain(0..6)));
+}
+
+#[test]
+fn splice_segmented() {
+    let mut src = Rb::<Static<i32, 4>>::default();
+    let mut dst = Rb::<Static<i32, 8>>::default();
+    let (mut src_prod, mut src_cons) = src.split_ref();
+    let (mut dst_prod, mut dst_cons) = dst.split_ref();
+
+    assert_eq!(src_prod.push_iter(0..3), 3);
+    assert_eq!(src_cons.skip(3), 3);
+    assert_eq!(src_prod.push_iter(3..7), 4);
+    assert_eq!(dst_prod.push_iter(0..6), 6);
+    assert_eq!(dst_cons.skip(6), 6);
+
+    let (left, right) = src_cons.as_slices();
+    assert_eq!((left.len(), right.len()), (1, 3));
+
+    assert_eq!(src_cons.splice_into(&mut dst_prod, 3), 3);
+    assert!(src_cons.iter().copied().eq(6..7));
+    assert!(dst_cons.iter().copied().eq(3..6));
+    let (left, right) = dst_cons.as_slices();
+    assert_eq!((left.len(), right.len()), (2, 1));
+}
+
+#[test]
+fn drain_into_all() {
+    let mut src = Rb::<Static<i32, 6>>::default();
+    let mut dst = Rb::<Static<i32, 4>>::default();
+    let (mut src_prod, mut src_cons) = src.split_ref();
+    let (mut dst_prod, mut dst_cons) = dst.split_ref();
+
+    assert_eq!(src_prod.push_iter(0..6), 6);
+    assert_eq!(dst_prod.try_push(-1), Ok(()));
+
+    assert_eq!(src_cons.drain_into_all(&mut dst_prod), 3);
+    assert!(!src_cons.is_empty());
+    assert!(src_cons.iter().copied().eq(3..6));
+    assert!(dst_cons.pop_iter().eq([-1, 0, 1, 2]));
+
+    assert_eq!(src_cons.drain_into_all(&mut dst_prod), 3);
+    assert!(src_cons.is_empty());
+    assert!(dst_cons.iter().copied().eq(3..6));
+}
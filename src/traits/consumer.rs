@@ -1,8 +1,24 @@
-use super::{utils::modulus, Observer};
-use crate::utils::{slice_assume_init_mut, slice_assume_init_ref, write_uninit_slice};
-use core::{iter::Chain, mem::MaybeUninit, ptr, slice};
+use super::{
+    utils::{modulus, Label},
+    Observer, Producer,
+};
+#[cfg(feature = "alloc")]
+use crate::rb::FrozenRb;
+use crate::{
+    transfer::transfer,
+    utils::{slice_assume_init_mut, slice_assume_init_ref, write_uninit_slice},
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{
+    array, fmt,
+    iter::{self, Chain},
+    mem::MaybeUninit,
+    ops::ControlFlow,
+    option, ptr, slice,
+};
 #[cfg(feature = "std")]
-use std::io::{self, Write};
+use std::io::{self, IoSlice, Read, Seek, SeekFrom, Write};
 
 /// Consumer part of ring buffer.
 ///
@@ -22,12 +38,36 @@ pub trait Consumer: Observer {
     /// # Safety
     ///
     /// First `count` items in occupied memory must be moved out or dropped.
+    /// In particular, there must be no live references to them (e.g. obtained from [`Self::as_slices`]).
     ///
     /// Must not be called concurrently.
     unsafe fn advance_read_index(&self, count: usize) {
         self.set_read_index((self.read_index() + count) % modulus(self));
     }
 
+    /// Moves `read` pointer by `count` places backward returning previously removed items back to the ring buffer.
+    ///
+    /// Returns `false` and does nothing if there are less than `count` vacant places, i.e. some of those items have already been overwritten by the producer.
+    ///
+    /// # Safety
+    ///
+    /// Last `count` places before `read` pointer must contain items being previously removed from the ring buffer.
+    /// Items are bitwise copied back, that's why they are required to be [`Copy`].
+    ///
+    /// The producer must not be used concurrently, and it must not have read the `read` pointer since the items were removed
+    /// (e.g. a caching producer may keep it and overwrite returned items afterwards).
+    unsafe fn try_rewind_read(&self, count: usize) -> bool
+    where
+        Self::Item: Copy,
+    {
+        if count > self.vacant_len() {
+            return false;
+        }
+        let modulus = modulus(self);
+        self.set_read_index((modulus.get() + self.read_index() - count) % modulus);
+        true
+    }
+
     /// Provides a direct access to the ring buffer occupied memory.
     /// The difference from [`Self::as_slices`] is that this method provides slices of [`MaybeUninit`], so items may be moved out of slices.  
     ///
@@ -57,7 +97,90 @@ pub trait Consumer: Observer {
         self.unsafe_slices(self.read_index(), self.write_index())
     }
 
+    /// Passes occupied slices to `f` and moves `read` pointer by the number of items `f` returns.
+    ///
+    /// Items must be removed the same way as for [`Self::occupied_slices`].
+    ///
+    /// Returns count of items been removed from the ring buffer.
+    ///
+    /// # Safety
+    ///
+    /// `f` must move out or drop exactly as many first occupied items as it returns, and must not move out or drop any other items.
+    ///
+    /// *Panics if `f` returns more than the number of occupied places.*
+    unsafe fn consume_with_slices<F: FnOnce(&[MaybeUninit<Self::Item>], &[MaybeUninit<Self::Item>]) -> usize>(&mut self, f: F) -> usize {
+        let (left, right) = self.occupied_slices();
+        let len = left.len() + right.len();
+        let count = f(left, right);
+        assert!(count <= len, "{}more items consumed than occupied", Label(self.label()));
+        self.advance_read_index(count);
+        count
+    }
+
+    /// Returns lengths of the slices returned by [`Self::occupied_slices`], the second one may be zero.
+    ///
+    /// Computed from indices only, without accessing the storage.
+    fn occupied_layout(&self) -> (usize, usize) {
+        let len = self.occupied_len();
+        let first = usize::min(len, self.capacity().get() - self.read_index() % self.capacity());
+        (first, len - first)
+    }
+
+    /// Checks whether all items are stored in a single contiguous slice, i.e. the second occupied slice is empty.
+    ///
+    /// Empty ring buffer is contiguous.
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        self.occupied_layout().1 == 0
+    }
+
+    /// The number of items that can be popped contiguously before read position wraps around to the beginning of the storage.
+    ///
+    /// Unlike [`Observer::pops_until_wrap`] it is bounded by [`Observer::occupied_len`],
+    /// so it is the length of the first slice returned by [`Self::as_slices`].
+    fn read_slots_before_wrap(&self) -> usize {
+        usize::min(self.pops_until_wrap(), self.occupied_len())
+    }
+
+    /// Same as [`Observer::occupied_len`] but loads own `read` index with relaxed memory ordering.
+    ///
+    /// Only the `write` index of the producer needs to be synchronized, so it's cheaper on some platforms
+    /// when the consumer polls the ring buffer in a tight loop.
+    #[inline]
+    fn len_relaxed(&self) -> usize {
+        let modulus = modulus(self);
+        (modulus.get() + self.write_index() - self.read_index_relaxed()) % modulus
+    }
+
     /// Returns a pair of slices which contain, in order, the contents of the ring buffer.
+    ///
+    /// The slices stay valid while the producer concurrently appends items:
+    /// the producer writes only to vacant places, and occupied places become vacant only when the consumer removes items.
+    /// Items appended after the call are not visible in the slices.
+    ///
+    /// The slices borrow the consumer, so items cannot be removed while the slices are alive:
+    ///
+    /// ```compile_fail
+    /// # use ringbuf::{LocalRb, storage::Static, traits::*};
+    /// let mut rb = LocalRb::<Static<i32, 4>>::default();
+    /// let (mut prod, mut cons) = rb.split_ref();
+    /// prod.try_push(1).unwrap();
+    ///
+    /// let (left, _) = cons.as_slices();
+    /// cons.try_pop();
+    /// assert_eq!(left[0], 1);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use ringbuf::{SharedRb, storage::Static, traits::*};
+    /// let mut rb = SharedRb::<Static<i32, 4>>::default();
+    /// let (mut prod, mut cons) = rb.split_ref();
+    /// prod.try_push(1).unwrap();
+    ///
+    /// let (left, _) = cons.as_slices();
+    /// cons.skip(1);
+    /// assert_eq!(left[0], 1);
+    /// ```
     #[inline]
     fn as_slices(&self) -> (&[Self::Item], &[Self::Item]) {
         unsafe {
@@ -66,6 +189,24 @@ pub trait Consumer: Observer {
         }
     }
 
+    /// Returns references to the oldest and the newest items in the ring buffer.
+    ///
+    /// If there is only one item then both references point to it. Returns `None` if the ring buffer is empty.
+    fn ends(&self) -> Option<(&Self::Item, &Self::Item)> {
+        let (left, right) = self.as_slices();
+        Some((left.first()?, right.last().or(left.last())?))
+    }
+
+    /// Returns a pair of byte slices which contain, in order, the contents of the ring buffer.
+    #[cfg(feature = "bytemuck")]
+    fn as_byte_slices(&self) -> (&[u8], &[u8])
+    where
+        Self::Item: bytemuck::Pod,
+    {
+        let (left, right) = self.as_slices();
+        (bytemuck::cast_slice(left), bytemuck::cast_slice(right))
+    }
+
     /// Returns a pair of mutable slices which contain, in order, the contents of the ring buffer.
     #[inline]
     fn as_mut_slices(&mut self) -> (&mut [Self::Item], &mut [Self::Item]) {
@@ -80,8 +221,10 @@ pub trait Consumer: Observer {
     /// Returns `None` if the ring buffer is empty.
     fn try_pop(&mut self) -> Option<Self::Item> {
         if !self.is_empty() {
-            let elem = unsafe { self.occupied_slices().0.get_unchecked(0).assume_init_read() };
-            unsafe { self.advance_read_index(1) };
+            // Only the single slot at the read index is accessed, so there is no need to compute both occupied slices.
+            let read = self.read_index();
+            let elem = unsafe { self.unsafe_slices(read, read + 1).0.get_unchecked(0).assume_init_read() };
+            unsafe { self.set_read_index((read + 1) % modulus(self)) };
             Some(elem)
         } else {
             None
@@ -115,6 +258,64 @@ pub trait Consumer: Observer {
         count
     }
 
+    /// Moves at most `out.len()` oldest items from the ring buffer into the beginning of `out`.
+    ///
+    /// Unlike [`Self::pop_slice`] items aren't required to be [`Copy`].
+    /// Moved items are owned by `out` now, so it's up to the caller to drop them.
+    ///
+    /// Returns count of items been moved.
+    fn take_slice(&mut self, out: &mut [MaybeUninit<Self::Item>]) -> usize {
+        let (left, right) = self.occupied_slices();
+        let left_count = usize::min(left.len(), out.len());
+        let right_count = usize::min(right.len(), out.len() - left_count);
+        unsafe {
+            ptr::copy_nonoverlapping(left.as_ptr(), out.as_mut_ptr(), left_count);
+            ptr::copy_nonoverlapping(right.as_ptr(), out.as_mut_ptr().add(left_count), right_count);
+            self.advance_read_index(left_count + right_count);
+        }
+        left_count + right_count
+    }
+
+    /// Returns a copy of `M` oldest items without removing them from the ring buffer.
+    ///
+    /// Returns `None` if the ring buffer contains less than `M` items.
+    fn peek_array<const M: usize>(&self) -> Option<[Self::Item; M]>
+    where
+        Self::Item: Copy,
+    {
+        if self.occupied_len() < M {
+            return None;
+        }
+        let mut items = self.iter();
+        Some(array::from_fn(|_| *items.next().unwrap()))
+    }
+
+    /// Removes frames of `CH` interleaved items from the ring buffer and writes each item of a frame into the corresponding output slice.
+    ///
+    /// The number of frames removed is limited by the length of the shortest output slice.
+    /// Incomplete trailing frame remains in the ring buffer.
+    ///
+    /// Returns count of frames been removed.
+    ///
+    /// *Panics if `CH` is zero.*
+    fn pop_deinterleave<const CH: usize>(&mut self, mut outs: [&mut [Self::Item]; CH]) -> usize
+    where
+        Self::Item: Copy,
+    {
+        assert!(CH > 0);
+        let count = outs
+            .iter()
+            .fold(self.occupied_len() / CH, |count, out| usize::min(count, out.len()));
+        let mut items = self.iter();
+        for frame in 0..count {
+            for out in outs.iter_mut() {
+                out[frame] = *items.next().unwrap();
+            }
+        }
+        unsafe { self.advance_read_index(count * CH) };
+        count
+    }
+
     fn into_iter(self) -> IntoIter<Self> {
         IntoIter::new(self)
     }
@@ -124,14 +325,111 @@ pub trait Consumer: Observer {
         PopIter::new(self)
     }
 
+    /// Returns an iterator that removes items which are currently stored in the ring buffer and stops when they are over.
+    ///
+    /// The iterator never waits for new items, like `try_iter` of `std::sync::mpsc::Receiver`.
+    /// Items pushed after the iterator has been created are not yielded by it.
+    ///
+    /// Same as [`Self::pop_iter`].
+    fn try_iter(&mut self) -> PopIter<'_, Self> {
+        self.pop_iter()
+    }
+
+    /// Returns an iterator that removes and yields items for which `filter` returns `true`.
+    ///
+    /// Remaining items keep their order.
+    /// If the iterator is dropped before it is exhausted then the rest of items are kept.
+    fn drain_filter<F: FnMut(&Self::Item) -> bool>(&mut self, filter: F) -> DrainFilter<'_, Self, F> {
+        DrainFilter::new(self, filter)
+    }
+
+    /// Copies items stored in the ring buffer into an immutable contiguous [`FrozenRb`].
+    ///
+    /// Items are not removed from the ring buffer, and the copy isn't affected by its later modifications.
+    ///
+    /// For a ring buffer shared between threads this is the way to take a snapshot of its contents while the producer is active,
+    /// because only the consumer may read stored items.
+    #[cfg(feature = "alloc")]
+    fn freeze(&self) -> FrozenRb<Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    /// Wraps the consumer into an adapter that converts items with `f` when they are removed.
+    ///
+    /// See [`MappedCons`].
+    fn map_items<U, F: Fn(Self::Item) -> U>(self, f: F) -> MappedCons<Self, F>
+    where
+        Self: Sized,
+    {
+        MappedCons::new(self, f)
+    }
+
     /// Returns a front-to-back iterator containing references to items in the ring buffer.
     ///
     /// This iterator does not remove items out of the ring buffer.
+    /// Like [`Self::as_slices`] it stays valid while the producer concurrently appends items.
     fn iter(&self) -> Iter<'_, Self> {
         let (left, right) = self.as_slices();
         left.iter().chain(right.iter())
     }
 
+    /// Returns a parallel iterator containing references to items in the ring buffer.
+    ///
+    /// Both occupied slices are split into parallel chunks, items are indexed from the oldest to the newest.
+    ///
+    /// This iterator does not remove items out of the ring buffer.
+    #[cfg(feature = "rayon")]
+    fn par_iter(&self) -> ParIter<'_, Self>
+    where
+        Self::Item: Sync,
+    {
+        use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+        let (left, right) = self.as_slices();
+        left.into_par_iter().chain(right)
+    }
+
+    /// Returns a front-to-back iterator over copies of items in the ring buffer.
+    ///
+    /// This iterator does not remove items out of the ring buffer.
+    fn copied(&self) -> iter::Copied<Iter<'_, Self>>
+    where
+        Self::Item: Copy,
+    {
+        self.iter().copied()
+    }
+
+    /// Returns a front-to-back iterator containing references to items in the ring buffer along with their logical positions.
+    ///
+    /// The position is counted from the oldest item which has position `0`, regardless of where the item is placed in the storage.
+    fn enumerate_iter(&self) -> iter::Enumerate<Iter<'_, Self>> {
+        self.iter().enumerate()
+    }
+
+    /// Folds items from the oldest to the newest without removing them from the ring buffer.
+    fn fold<B, F: FnMut(B, &Self::Item) -> B>(&self, init: B, f: F) -> B {
+        self.iter().fold(init, f)
+    }
+
+    /// Sums items without removing them from the ring buffer.
+    fn sum<S>(&self) -> S
+    where
+        S: for<'a> iter::Sum<&'a Self::Item>,
+    {
+        self.iter().sum()
+    }
+
+    /// Multiplies items without removing them from the ring buffer.
+    fn product<P>(&self) -> P
+    where
+        P: for<'a> iter::Product<&'a Self::Item>,
+    {
+        self.iter().product()
+    }
+
     /// Returns a front-to-back iterator that returns mutable references to items in the ring buffer.
     ///
     /// This iterator does not remove items out of the ring buffer.
@@ -140,6 +438,189 @@ pub trait Consumer: Observer {
         left.iter_mut().chain(right.iter_mut())
     }
 
+    /// Returns a reference to the maximum item in the ring buffer without removing it.
+    ///
+    /// If several items are equally maximum then the newest one is returned.
+    /// Returns `None` if the ring buffer is empty.
+    fn max(&self) -> Option<&Self::Item>
+    where
+        Self::Item: Ord,
+    {
+        self.iter().max()
+    }
+
+    /// Returns a reference to the minimum item in the ring buffer without removing it.
+    ///
+    /// If several items are equally minimum then the oldest one is returned.
+    /// Returns `None` if the ring buffer is empty.
+    fn min(&self) -> Option<&Self::Item>
+    where
+        Self::Item: Ord,
+    {
+        self.iter().min()
+    }
+
+    /// Returns a reference to the item that gives the maximum value from `f` without removing it.
+    ///
+    /// If several items are equally maximum then the newest one is returned.
+    /// Returns `None` if the ring buffer is empty.
+    fn max_by_key<K: Ord, F: FnMut(&Self::Item) -> K>(&self, mut f: F) -> Option<&Self::Item> {
+        self.iter().max_by_key(|item| f(item))
+    }
+
+    /// Returns a reference to the item that gives the minimum value from `f` without removing it.
+    ///
+    /// If several items are equally minimum then the oldest one is returned.
+    /// Returns `None` if the ring buffer is empty.
+    fn min_by_key<K: Ord, F: FnMut(&Self::Item) -> K>(&self, mut f: F) -> Option<&Self::Item> {
+        self.iter().min_by_key(|item| f(item))
+    }
+
+    /// Clones at most `max` oldest items onto the end of `out` without removing them from the ring buffer.
+    ///
+    /// Returns count of items been cloned.
+    #[cfg(feature = "alloc")]
+    fn extend_vec(&self, out: &mut Vec<Self::Item>, max: usize) -> usize
+    where
+        Self::Item: Clone,
+    {
+        let (left, right) = self.as_slices();
+        let count = usize::min(max, left.len() + right.len());
+        out.reserve(count);
+        if count <= left.len() {
+            out.extend_from_slice(&left[..count]);
+        } else {
+            out.extend_from_slice(left);
+            out.extend_from_slice(&right[..(count - left.len())]);
+        }
+        count
+    }
+
+    /// Removes items one by one passing them to `f` until the ring buffer is empty or `f` returns [`ControlFlow::Break`].
+    ///
+    /// The item for which `f` returned `Break` is also removed, items after it remain in the ring buffer.
+    ///
+    /// Returns the number of removed items.
+    fn drain_each<F: FnMut(Self::Item) -> ControlFlow<()>>(&mut self, mut f: F) -> usize {
+        let mut count = 0;
+        while let Some(item) = self.try_pop() {
+            count += 1;
+            if f(item).is_break() {
+                break;
+            }
+        }
+        count
+    }
+
+    /// Removes all items from the ring buffer and returns them in order.
+    ///
+    /// Unlike [`Self::clear`] the items are moved out instead of being dropped.
+    #[cfg(feature = "alloc")]
+    fn drain_all(&mut self) -> Vec<Self::Item> {
+        self.pop_iter().collect()
+    }
+
+    /// Moves at most `max` oldest items from the ring buffer to the end of `out`.
+    ///
+    /// Capacity of `out` is reserved before moving, so items are moved with at most two memory copies.
+    ///
+    /// Returns count of items been moved.
+    #[cfg(feature = "alloc")]
+    fn pop_into_vec(&mut self, out: &mut Vec<Self::Item>, max: usize) -> usize {
+        let count = usize::min(max, self.occupied_len());
+        out.reserve(count);
+        let count = self.take_slice(&mut out.spare_capacity_mut()[..count]);
+        unsafe { out.set_len(out.len() + count) };
+        count
+    }
+
+    /// Removes all items from the ring buffer keeping only every `factor`-th one.
+    ///
+    /// Repeatedly drops `factor - 1` items and keeps the next one. Kept items are returned in order,
+    /// trailing items that don't form a complete group of `factor` items are dropped too.
+    ///
+    /// `factor == 0` is treated as `1`, i.e. all items are kept.
+    #[cfg(feature = "alloc")]
+    fn decimate(&mut self, factor: usize) -> Vec<Self::Item> {
+        let factor = usize::max(factor, 1);
+        let len = self.occupied_len();
+        let mut out = Vec::with_capacity(len / factor);
+        for i in 1..=len {
+            let item = unsafe { self.try_pop().unwrap_unchecked() };
+            if i % factor == 0 {
+                out.push(item);
+            }
+        }
+        out
+    }
+
+    /// Returns an iterator over non-empty contiguous chunks of the ring buffer contents from the newest chunk to the oldest one.
+    ///
+    /// Only the order of chunks is reversed, items within each chunk remain ordered from the oldest to the newest.
+    fn rchunks(&self) -> RChunks<'_, Self> {
+        let (left, right) = self.as_slices();
+        let non_empty = |s: &&[Self::Item]| !s.is_empty();
+        Some(right).filter(non_empty).into_iter().chain(Some(left).filter(non_empty))
+    }
+
+    /// Returns an iterator over contiguous chunks of exactly `lane` items from the oldest to the newest.
+    ///
+    /// Each occupied slice is split into chunks separately, so no chunk crosses the end of the storage.
+    /// Items that don't fit into a whole chunk at the end of each slice are not yielded
+    /// and should be handled separately (e.g. by scalar code).
+    ///
+    /// *Panics if `lane` is zero.*
+    fn occupied_chunks_exact(&self, lane: usize) -> ChunksExact<'_, Self> {
+        let (left, right) = self.as_slices();
+        left.chunks_exact(lane).chain(right.chunks_exact(lane))
+    }
+
+    /// Moves at most `count` items from `self` to the `dst` producer.
+    ///
+    /// If the items being moved are contiguous both in `self` and in `dst` then they are moved with a single memory copy,
+    /// otherwise it falls back to [`transfer`](`crate::transfer`).
+    ///
+    /// Returns number of items been moved.
+    fn splice_into<P: Producer<Item = Self::Item>>(&mut self, dst: &mut P, count: usize) -> usize {
+        let count = usize::min(count, usize::min(self.occupied_len(), dst.vacant_len()));
+        let (src_left, _) = self.occupied_slices();
+        let (dst_left, _) = dst.vacant_slices_mut();
+        if count <= src_left.len() && count <= dst_left.len() {
+            unsafe {
+                ptr::copy_nonoverlapping(src_left.as_ptr(), dst_left.as_mut_ptr(), count);
+                self.advance_read_index(count);
+                dst.advance_write_index(count);
+            }
+            count
+        } else {
+            transfer(self, dst, Some(count))
+        }
+    }
+
+    /// Moves as many items as `dst` can hold from `self` to `dst`.
+    ///
+    /// Same as [`transfer(self, dst, None)`](`crate::transfer`).
+    ///
+    /// Returns number of items been moved.
+    /// Items that haven't fit into `dst` remain in `self`, so [`Self::is_empty`] tells whether everything was moved.
+    fn drain_into_all<P: Producer<Item = Self::Item>>(&mut self, dst: &mut P) -> usize {
+        transfer(self, dst, None)
+    }
+
+    /// Returns the index of the first item (counting from the oldest one) for which `pred` returns `false`.
+    ///
+    /// The contents must be partitioned by `pred`, i.e. all items for which it returns `true` precede all items for which it returns `false`.
+    /// If this is not the case the result is unspecified.
+    ///
+    /// Binary search is used, so it takes `O(log(len))` predicate calls.
+    fn partition_point<F: FnMut(&Self::Item) -> bool>(&self, mut pred: F) -> usize {
+        let (left, right) = self.as_slices();
+        match left.last() {
+            Some(last) if pred(last) => left.len() + right.partition_point(pred),
+            _ => left.partition_point(pred),
+        }
+    }
+
     /// Removes at most `count` and at least `min(count, Self::len())` items from the buffer and safely drops them.
     ///
     /// If there is no concurring producer activity then exactly `min(count, Self::len())` items are removed.
@@ -186,6 +667,39 @@ pub trait Consumer: Observer {
         }
     }
 
+    /// Removes and safely drops all items except the newest `keep_last` ones.
+    ///
+    /// Returns the number of deleted items.
+    fn skip_all_or(&mut self, keep_last: usize) -> usize {
+        let count = self.occupied_len().saturating_sub(keep_last);
+        self.skip(count)
+    }
+
+    /// Returns the number of leading items (starting from the oldest one) that satisfy `pred` without removing them.
+    ///
+    /// Scanning stops at the first item that doesn't satisfy `pred`.
+    fn peek_run_len<F: FnMut(&Self::Item) -> bool>(&self, mut pred: F) -> usize {
+        self.iter().take_while(|item| pred(item)).count()
+    }
+
+    /// Removes the leading run of equal items from the ring buffer.
+    ///
+    /// Returns the first item of the run and the number of removed items (including the returned one),
+    /// or `None` if the ring buffer is empty.
+    fn pop_run(&mut self) -> Option<(Self::Item, usize)>
+    where
+        Self::Item: PartialEq,
+    {
+        let count = {
+            let mut iter = self.iter();
+            let first = iter.next()?;
+            1 + iter.take_while(|item| *item == first).count()
+        };
+        let value = self.try_pop()?;
+        self.skip(count - 1);
+        Some((value, count))
+    }
+
     #[cfg(feature = "std")]
     /// Removes at most first `count` bytes from the ring buffer and writes them into a [`Write`] instance.
     /// If `count` is `None` then as much as possible bytes will be written.
@@ -204,7 +718,44 @@ pub trait Consumer: Observer {
         let left_init = unsafe { slice_assume_init_ref(&left[..count]) };
 
         let write_count = writer.write(left_init)?;
-        assert!(write_count <= count);
+        assert!(
+            write_count <= count,
+            "{}writer returned more than buffer length",
+            Label(self.label())
+        );
+        unsafe { self.advance_read_index(write_count) };
+        Ok(write_count)
+    }
+
+    #[cfg(feature = "std")]
+    /// Removes at most first `count` bytes from the ring buffer and writes both occupied slices at once into a [`Write`] instance.
+    /// If `count` is `None` then as much as possible bytes will be written.
+    ///
+    /// Unlike [`Self::write_into`] it uses [`Write::write_vectored`], so the wrapped contents can be written in a single call.
+    /// If the writer accepts only a part of the bytes then exactly that number of bytes is removed.
+    ///
+    /// Returns `Ok(n)` if `write_vectored` succeeded. `n` is number of bytes been written.
+    /// `n == 0` means that either `write_vectored` returned zero or ring buffer is empty.
+    fn write_into_vectored<S: Write>(&mut self, writer: &mut S, count: Option<usize>) -> io::Result<usize>
+    where
+        Self: Consumer<Item = u8>,
+    {
+        let (left, right) = self.occupied_slices();
+        let count = usize::min(count.unwrap_or(usize::MAX), left.len() + right.len());
+        let left_count = usize::min(count, left.len());
+        let (left_init, right_init) = unsafe {
+            (
+                slice_assume_init_ref(&left[..left_count]),
+                slice_assume_init_ref(&right[..(count - left_count)]),
+            )
+        };
+
+        let write_count = writer.write_vectored(&[IoSlice::new(left_init), IoSlice::new(right_init)])?;
+        assert!(
+            write_count <= count,
+            "{}writer returned more than buffer length",
+            Label(self.label())
+        );
         unsafe { self.advance_read_index(write_count) };
         Ok(write_count)
     }
@@ -281,6 +832,251 @@ impl<'a, C: Consumer> Drop for PopIter<'a, C> {
     }
 }
 
+/// An iterator that removes items matching the filter from the ring buffer.
+pub struct DrainFilter<'a, C: Consumer, F: FnMut(&C::Item) -> bool> {
+    target: &'a C,
+    slices: (&'a mut [MaybeUninit<C::Item>], &'a mut [MaybeUninit<C::Item>]),
+    len: usize,
+    index: usize,
+    removed: usize,
+    filter: F,
+}
+impl<'a, C: Consumer, F: FnMut(&C::Item) -> bool> DrainFilter<'a, C, F> {
+    pub fn new(target: &'a mut C, filter: F) -> Self {
+        let slices = unsafe { target.unsafe_slices(target.read_index(), target.write_index()) };
+        Self {
+            len: slices.0.len() + slices.1.len(),
+            slices,
+            target,
+            index: 0,
+            removed: 0,
+            filter,
+        }
+    }
+    /// Pointer to an item by its position counting from the oldest one.
+    fn slot(&mut self, index: usize) -> *mut C::Item {
+        match index.checked_sub(self.slices.0.len()) {
+            None => self.slices.0[index].as_mut_ptr(),
+            Some(index) => self.slices.1[index].as_mut_ptr(),
+        }
+    }
+}
+impl<'a, C: Consumer, F: FnMut(&C::Item) -> bool> Iterator for DrainFilter<'a, C, F> {
+    type Item = C::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.len {
+            let ptr = self.slot(self.index);
+            let matched = (self.filter)(unsafe { &*ptr });
+            self.index += 1;
+            if matched {
+                self.removed += 1;
+                return Some(unsafe { ptr.read() });
+            } else if self.removed > 0 {
+                let dst = self.slot(self.index - 1 - self.removed);
+                unsafe { ptr::copy_nonoverlapping(ptr, dst, 1) };
+            }
+        }
+        None
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.len - self.index))
+    }
+}
+impl<'a, C: Consumer, F: FnMut(&C::Item) -> bool> Drop for DrainFilter<'a, C, F> {
+    fn drop(&mut self) {
+        if self.removed == 0 {
+            return;
+        }
+        // Close the gaps left by removed items.
+        for i in self.index..self.len {
+            let (src, dst) = (self.slot(i), self.slot(i - self.removed));
+            unsafe { ptr::copy_nonoverlapping(src, dst, 1) };
+        }
+        // Move remaining items to the end, so that vacant places are at the beginning.
+        for i in (0..(self.len - self.removed)).rev() {
+            let (src, dst) = (self.slot(i), self.slot(i + self.removed));
+            unsafe { ptr::copy_nonoverlapping(src, dst, 1) };
+        }
+        unsafe { self.target.advance_read_index(self.removed) };
+    }
+}
+
+/// Consumer adapter that converts items with `f` when they are removed from the ring buffer.
+///
+/// Items are stored unchanged and converted lazily on removal, so the ring buffer doesn't need to hold the converted type.
+pub struct MappedCons<C: Consumer, F> {
+    consumer: C,
+    f: F,
+}
+impl<C: Consumer, U, F: Fn(C::Item) -> U> MappedCons<C, F> {
+    /// Wraps `consumer` converting each removed item with `f`.
+    ///
+    /// Same as [`Consumer::map_items`].
+    pub fn new(consumer: C, f: F) -> Self {
+        Self { consumer, f }
+    }
+    /// Returns underlying consumer.
+    pub fn into_inner(self) -> C {
+        self.consumer
+    }
+    /// The number of items stored in the ring buffer.
+    pub fn occupied_len(&self) -> usize {
+        self.consumer.occupied_len()
+    }
+    /// Checks if the ring buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.consumer.is_empty()
+    }
+    /// Removes the oldest item from the ring buffer and converts it.
+    pub fn try_pop(&mut self) -> Option<U> {
+        self.consumer.try_pop().map(&self.f)
+    }
+    /// Returns an iterator that removes items which are currently stored in the ring buffer and converts them.
+    ///
+    /// See [`Consumer::try_iter`].
+    pub fn try_iter(&mut self) -> iter::Map<PopIter<'_, C>, &F> {
+        self.consumer.try_iter().map(&self.f)
+    }
+}
+
+/// Adapter that reads items from the consumer as bytes.
+///
+/// Each item is serialized lazily with `serialize` function when the previous item's bytes are exhausted.
+/// Bytes of a single item may be delivered across multiple [`Read::read`] calls if the buffer is too small.
+#[cfg(feature = "std")]
+pub struct ReadAdapter<C: Consumer, F: Fn(&C::Item) -> &[u8]> {
+    consumer: C,
+    serialize: F,
+    current: Option<C::Item>,
+    offset: usize,
+}
+#[cfg(feature = "std")]
+impl<C: Consumer, F: Fn(&C::Item) -> &[u8]> ReadAdapter<C, F> {
+    pub fn new(consumer: C, serialize: F) -> Self {
+        Self {
+            consumer,
+            serialize,
+            current: None,
+            offset: 0,
+        }
+    }
+    /// Returns underlying consumer.
+    ///
+    /// *The item which bytes are partially read is dropped.*
+    pub fn into_inner(self) -> C {
+        self.consumer
+    }
+}
+#[cfg(feature = "std")]
+impl<C: Consumer, F: Fn(&C::Item) -> &[u8]> Read for ReadAdapter<C, F> {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        let mut count = 0;
+        while count < buffer.len() {
+            let item = match &self.current {
+                Some(item) => item,
+                None => match self.consumer.try_pop() {
+                    Some(item) => {
+                        self.offset = 0;
+                        self.current.insert(item)
+                    }
+                    None => break,
+                },
+            };
+            let bytes = &(self.serialize)(item)[self.offset..];
+            let n = usize::min(bytes.len(), buffer.len() - count);
+            buffer[count..(count + n)].copy_from_slice(&bytes[..n]);
+            count += n;
+            if n == bytes.len() {
+                self.current = None;
+            } else {
+                self.offset += n;
+            }
+        }
+        if count == 0 && !buffer.is_empty() {
+            Err(io::ErrorKind::WouldBlock.into())
+        } else {
+            Ok(count)
+        }
+    }
+}
+
+/// Byte reader that can seek within bytes currently stored in the ring buffer.
+///
+/// The reader keeps a virtual cursor separate from the read index of the consumer.
+/// [`Read::read`] copies bytes at the cursor and advances it, but bytes are removed from the ring buffer (committed)
+/// only when more than `keep` bytes are behind the cursor, so the last `keep` read bytes can be read again after seeking back.
+///
+/// Positions used by [`Seek`] are relative to the oldest uncommitted byte, so the position decreases when bytes are committed.
+/// Seeking beyond the stored bytes is an error.
+#[cfg(feature = "std")]
+pub struct SeekableCons<C: Consumer<Item = u8>> {
+    consumer: C,
+    cursor: usize,
+    keep: usize,
+}
+#[cfg(feature = "std")]
+impl<C: Consumer<Item = u8>> SeekableCons<C> {
+    /// Wraps the consumer keeping at most `keep` already read bytes in the ring buffer.
+    pub fn new(consumer: C, keep: usize) -> Self {
+        Self { consumer, cursor: 0, keep }
+    }
+    /// Position of the cursor relative to the oldest uncommitted byte.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+    /// Removes all bytes behind the cursor from the ring buffer.
+    pub fn commit(&mut self) {
+        self.cursor -= self.consumer.skip(self.cursor);
+    }
+    /// Returns underlying consumer.
+    ///
+    /// *Bytes behind the cursor are not removed.*
+    pub fn into_inner(self) -> C {
+        self.consumer
+    }
+}
+#[cfg(feature = "std")]
+impl<C: Consumer<Item = u8>> Read for SeekableCons<C> {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        let (left, right) = self.consumer.as_slices();
+        let (left, right) = match left.len().checked_sub(self.cursor) {
+            Some(rest) => (&left[(left.len() - rest)..], right),
+            None => (&right[(self.cursor - left.len())..], &[][..]),
+        };
+        let left_count = usize::min(left.len(), buffer.len());
+        buffer[..left_count].copy_from_slice(&left[..left_count]);
+        let right_count = usize::min(right.len(), buffer.len() - left_count);
+        buffer[left_count..(left_count + right_count)].copy_from_slice(&right[..right_count]);
+        let count = left_count + right_count;
+        if count == 0 && !buffer.is_empty() {
+            return Err(io::ErrorKind::WouldBlock.into());
+        }
+        self.cursor += count;
+        if self.cursor > self.keep {
+            self.cursor -= self.consumer.skip(self.cursor - self.keep);
+        }
+        Ok(count)
+    }
+}
+#[cfg(feature = "std")]
+impl<C: Consumer<Item = u8>> Seek for SeekableCons<C> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let (base, offset) = match pos {
+            SeekFrom::Start(offset) => (0, i64::try_from(offset).unwrap_or(i64::MAX)),
+            SeekFrom::Current(offset) => (self.cursor, offset),
+            SeekFrom::End(offset) => (self.consumer.occupied_len(), offset),
+        };
+        match base.checked_add_signed(offset as isize) {
+            Some(cursor) if cursor <= self.consumer.occupied_len() => {
+                self.cursor = cursor;
+                Ok(cursor as u64)
+            }
+            _ => Err(io::Error::new(io::ErrorKind::InvalidInput, "seek position is out of stored bytes")),
+        }
+    }
+}
+
 /// Iterator over ring buffer contents.
 ///
 /// *Please do not rely on actual type, it may change in future.*
@@ -293,10 +1089,73 @@ pub type Iter<'a, C: Consumer> = Chain<slice::Iter<'a, C::Item>, slice::Iter<'a,
 #[allow(type_alias_bounds)]
 pub type IterMut<'a, C: Consumer> = Chain<slice::IterMut<'a, C::Item>, slice::IterMut<'a, C::Item>>;
 
+/// Iterator over ring buffer chunks from the newest to the oldest.
+///
+/// *Please do not rely on actual type, it may change in future.*
+#[allow(type_alias_bounds)]
+pub type RChunks<'a, C: Consumer> = Chain<option::IntoIter<&'a [C::Item]>, option::IntoIter<&'a [C::Item]>>;
+
+/// Parallel iterator over ring buffer contents.
+///
+/// *Please do not rely on actual type, it may change in future.*
+#[cfg(feature = "rayon")]
+#[allow(type_alias_bounds)]
+pub type ParIter<'a, C: Consumer> = rayon::iter::Chain<rayon::slice::Iter<'a, C::Item>, rayon::slice::Iter<'a, C::Item>>;
+
+/// Iterator over contiguous chunks of ring buffer contents of exactly the same length.
+///
+/// *Please do not rely on actual type, it may change in future.*
+#[allow(type_alias_bounds)]
+pub type ChunksExact<'a, C: Consumer> = Chain<slice::ChunksExact<'a, C::Item>, slice::ChunksExact<'a, C::Item>>;
+
+/// Formats consumer contents for [`Debug`](`fmt::Debug`) implementations.
+///
+/// Alternate form (`{:#?}`) also shows the label (if any) and the physical layout: capacity, indices and lengths of occupied slices.
+#[doc(hidden)]
+pub fn fmt_debug<C: Consumer>(this: &C, name: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result
+where
+    C::Item: fmt::Debug,
+{
+    if f.alternate() {
+        let mut s = f.debug_struct(name);
+        if let Some(label) = this.label() {
+            s.field("label", &label);
+        }
+        s.field("capacity", &this.capacity())
+            .field("read_index", &this.read_index())
+            .field("write_index", &this.write_index())
+            .field("layout", &this.occupied_layout())
+            .field("items", &DebugItems(this))
+            .finish()
+    } else {
+        fmt::Debug::fmt(&DebugItems(this), f)
+    }
+}
+
+struct DebugItems<'a, C: Consumer>(&'a C);
+impl<'a, C: Consumer> fmt::Debug for DebugItems<'a, C>
+where
+    C::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.iter()).finish()
+    }
+}
+
 #[macro_export]
 macro_rules! impl_consumer_traits {
     ($type:ident $(< $( $param:tt $( : $first_bound:tt $(+ $next_bound:tt )* )? ),+ >)?) => {
 
+        impl $(< $( $param $( : $first_bound $(+ $next_bound )* )? ),+ >)? core::fmt::Debug for $type $(< $( $param ),+ >)?
+        where
+            Self: $crate::traits::Consumer,
+            <Self as $crate::traits::Observer>::Item: core::fmt::Debug,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                $crate::consumer::fmt_debug(self, stringify!($type), f)
+            }
+        }
+
         #[cfg(feature = "std")]
         impl $(< $( $param $( : $first_bound $(+ $next_bound )* )? ),+ >)? std::io::Read for $type $(< $( $param ),+ >)?
         where
@@ -0,0 +1,36 @@
+use super::Observer;
+
+/// Object-safe subset of [`Observer`] that provides ring buffer occupancy metrics.
+///
+/// It is implemented for everything that implements [`Observer`] (ring buffers, their producers, consumers and observers)
+/// regardless of the item type, so different ring buffers can be stored together as `Box<dyn RbMetrics>`.
+///
+/// *This trait isn't re-exported in [`crate::traits`] to avoid method name collisions with [`Observer`].*
+pub trait RbMetrics {
+    /// Capacity of the ring buffer.
+    fn capacity(&self) -> usize;
+    /// The number of items stored in the ring buffer.
+    fn len(&self) -> usize;
+    /// The number of vacant places in the ring buffer.
+    fn vacant(&self) -> usize;
+
+    /// Checks if the ring buffer is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<O: Observer> RbMetrics for O {
+    #[inline]
+    fn capacity(&self) -> usize {
+        Observer::capacity(self).get()
+    }
+    #[inline]
+    fn len(&self) -> usize {
+        self.occupied_len()
+    }
+    #[inline]
+    fn vacant(&self) -> usize {
+        self.vacant_len()
+    }
+}
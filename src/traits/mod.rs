@@ -1,4 +1,5 @@
 pub mod consumer;
+pub mod metrics;
 pub mod observer;
 pub mod producer;
 pub mod ring_buffer;
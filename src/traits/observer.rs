@@ -9,9 +9,39 @@ pub trait Observer: Sized {
     /// It is constant during the whole ring buffer lifetime.
     fn capacity(&self) -> NonZeroUsize;
 
+    /// Index of the oldest item (the next item to be removed).
+    ///
+    /// Indices are taken modulo `2 * capacity` (not just `capacity`), so `read_index() == write_index()` means that the ring buffer is empty
+    /// and `(write_index() - read_index()) % (2 * capacity)` is the number of items.
+    /// Item position in the storage is `index % capacity`.
+    ///
+    /// *The value may be outdated due to concurring activity of the consumer.*
     fn read_index(&self) -> usize;
+    /// Index of the next vacant place (where the next item will be put).
+    ///
+    /// Indices are in the same space as [`Self::read_index`].
+    ///
+    /// *The value may be outdated due to concurring activity of the producer.*
     fn write_index(&self) -> usize;
 
+    /// Same as [`Self::read_index`] but may use relaxed memory ordering.
+    ///
+    /// The `read` index is modified only by the consumer, so the consumer can load it without synchronization
+    /// (see [`Consumer::len_relaxed`](`super::Consumer::len_relaxed`)). Others should use [`Self::read_index`].
+    #[inline]
+    fn read_index_relaxed(&self) -> usize {
+        self.read_index()
+    }
+
+    /// Label of the ring buffer used in diagnostics (e.g. in [`Debug`](`core::fmt::Debug`) output and panic messages).
+    ///
+    /// Ring buffers have no label by default.
+    #[inline]
+    fn label(&self) -> Option<&'static str> {
+        None
+    }
+
+    #[allow(clippy::mut_from_ref)]
     unsafe fn unsafe_slices(&self, start: usize, end: usize) -> (&mut [MaybeUninit<Self::Item>], &mut [MaybeUninit<Self::Item>]);
 
     /// The number of items stored in the buffer.
@@ -30,6 +60,36 @@ pub trait Observer: Sized {
         (self.capacity().get() + self.read_index() - self.write_index()) % modulus
     }
 
+    /// The number of items that can be pushed before the buffer becomes full.
+    ///
+    /// Same as [`Self::vacant_len`].
+    #[inline]
+    fn distance_to_full(&self) -> usize {
+        self.vacant_len()
+    }
+
+    /// The number of items that can be popped before the buffer becomes empty.
+    ///
+    /// Same as [`Self::occupied_len`].
+    #[inline]
+    fn distance_to_empty(&self) -> usize {
+        self.occupied_len()
+    }
+
+    /// The number of pushes until write position wraps around to the beginning of the storage.
+    ///
+    /// It doesn't take vacant space into account, so it may be greater than [`Self::vacant_len`].
+    fn pushes_until_wrap(&self) -> usize {
+        self.capacity().get() - self.write_index() % self.capacity()
+    }
+
+    /// The number of pops until read position wraps around to the beginning of the storage.
+    ///
+    /// It doesn't take occupied space into account, so it may be greater than [`Self::occupied_len`].
+    fn pops_until_wrap(&self) -> usize {
+        self.capacity().get() - self.read_index() % self.capacity()
+    }
+
     /// Checks if the ring buffer is empty.
     ///
     /// *The result may become irrelevant at any time because of concurring producer activity.*
@@ -65,6 +125,15 @@ macro_rules! delegate_observer {
         fn write_index(&self) -> usize {
             $ref(self).write_index()
         }
+        #[inline]
+        fn read_index_relaxed(&self) -> usize {
+            $ref(self).read_index_relaxed()
+        }
+
+        #[inline]
+        fn label(&self) -> Option<&'static str> {
+            $ref(self).label()
+        }
 
         #[inline]
         unsafe fn unsafe_slices(
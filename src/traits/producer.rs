@@ -1,12 +1,26 @@
-use super::{utils::modulus, Observer};
+use super::{
+    utils::{modulus, Label},
+    Observer,
+};
 #[cfg(feature = "std")]
 use crate::utils::slice_assume_init_mut;
-use crate::utils::write_slice;
-use core::mem::MaybeUninit;
+use crate::utils::{write_iter, write_iter_fold, write_slice};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::hint::spin_loop;
+#[cfg(feature = "alloc")]
+use core::ptr;
+use core::{
+    iter::{self, Peekable},
+    mem::MaybeUninit,
+    ops::{Deref, DerefMut},
+};
 #[cfg(feature = "std")]
 use std::{
     cmp,
-    io::{self, Read},
+    io::{self, IoSliceMut, Read},
+    time::Instant,
 };
 
 /// Producer part of ring buffer.
@@ -33,6 +47,23 @@ pub trait Producer: Observer {
         self.set_write_index((self.write_index() + count) % modulus(self));
     }
 
+    /// Makes all items pushed so far visible to the consumer.
+    ///
+    /// Producers that store the `write` index in the ring buffer immediately (e.g. the ring buffer itself) do nothing here,
+    /// but those that batch index updates must store the index.
+    #[inline]
+    fn publish(&self) {}
+
+    /// Checks whether the consumer of the ring buffer has been dropped, so that pushed items will never be received.
+    ///
+    /// Only a dropped consumer half counts as disconnection (see [`RingBuffer::read_is_released`](`crate::traits::RingBuffer::read_is_released`)),
+    /// so a producer half is never disconnected while the ring buffer itself is used as its consumer.
+    /// Always returns `false` for producers that don't track their consumer (e.g. for the ring buffer itself).
+    #[inline]
+    fn is_disconnected(&self) -> bool {
+        false
+    }
+
     /// Provides a direct access to the ring buffer vacant memory.
     ///
     /// Returns a pair of slices of uninitialized memory, the second one may be empty.
@@ -52,14 +83,69 @@ pub trait Producer: Observer {
         unsafe { self.unsafe_slices(self.write_index(), self.read_index() + self.capacity().get()) }
     }
 
+    /// The number of items that can be pushed contiguously before write position wraps around to the beginning of the storage.
+    ///
+    /// Unlike [`Observer::pushes_until_wrap`] it is bounded by [`Observer::vacant_len`],
+    /// so it is the length of the first slice returned by [`Self::vacant_slices`].
+    fn write_slots_before_wrap(&self) -> usize {
+        usize::min(self.pushes_until_wrap(), self.vacant_len())
+    }
+
+    /// Provides a direct access to exactly `count` contiguous vacant items.
+    ///
+    /// If the first vacant slice is shorter than `count` then its length is returned in `Err`,
+    /// i.e. the largest number of items that can be reserved contiguously right now. No vacant space is skipped.
+    ///
+    /// *This method must be followed by [`Self::advance_write_index`] call with the number of items being put previously as argument.*
+    /// *No other mutating calls allowed before that.*
+    fn reserve_exact_contiguous(&mut self, count: usize) -> Result<&mut [MaybeUninit<Self::Item>], usize> {
+        let (left, _) = self.vacant_slices_mut();
+        if left.len() >= count {
+            Ok(&mut left[..count])
+        } else {
+            Err(left.len())
+        }
+    }
+
+    /// Returns lengths of the slices returned by [`Self::vacant_slices`], the second one may be zero.
+    ///
+    /// Computed from indices only, without accessing the storage.
+    fn vacant_layout(&self) -> (usize, usize) {
+        let len = self.vacant_len();
+        let first = usize::min(len, self.capacity().get() - self.write_index() % self.capacity());
+        (first, len - first)
+    }
+
+    /// Passes vacant slices to `f` and moves `write` pointer by the number of items `f` returns.
+    ///
+    /// Items must be put the same way as for [`Self::vacant_slices_mut`].
+    ///
+    /// Returns count of items been appended to the ring buffer.
+    ///
+    /// # Safety
+    ///
+    /// `f` must initialize at least as many first vacant places as it returns.
+    ///
+    /// *Panics if `f` returns more than the number of vacant places.*
+    unsafe fn push_with_slices<F: FnOnce(&mut [MaybeUninit<Self::Item>], &mut [MaybeUninit<Self::Item>]) -> usize>(&mut self, f: F) -> usize {
+        let (left, right) = self.vacant_slices_mut();
+        let len = left.len() + right.len();
+        let count = f(left, right);
+        assert!(count <= len, "{}more items produced than vacant", Label(self.label()));
+        self.advance_write_index(count);
+        count
+    }
+
     /// Appends an item to the ring buffer.
     ///
     /// If buffer is full returns an `Err` containing the item that hasn't been appended.
     fn try_push(&mut self, elem: Self::Item) -> Result<(), Self::Item> {
         if !self.is_full() {
+            // Only the single slot at the write index is accessed, so there is no need to compute both vacant slices.
+            let write = self.write_index();
             unsafe {
-                self.vacant_slices_mut().0.get_unchecked_mut(0).write(elem);
-                self.advance_write_index(1)
+                self.unsafe_slices(write, write + 1).0.get_unchecked_mut(0).write(elem);
+                self.set_write_index((write + 1) % modulus(self))
             };
             Ok(())
         } else {
@@ -67,6 +153,85 @@ pub trait Producer: Observer {
         }
     }
 
+    /// Writes an item to the ring buffer and returns a handle to it.
+    ///
+    /// The item can be modified through the handle, and it is appended to the ring buffer (becomes visible to the consumer)
+    /// only when the handle is dropped, so the consumer never reads the item while it is being modified.
+    /// The handle exclusively borrows the producer, so no other items can be pushed until it is dropped.
+    ///
+    /// If the handle is leaked (e.g. by [`core::mem::forget`]) then the item is never appended and is leaked too.
+    ///
+    /// If buffer is full returns an `Err` containing the item that hasn't been written.
+    fn push_ref(&mut self, elem: Self::Item) -> Result<WriteHandle<'_, Self>, Self::Item> {
+        if !self.is_full() {
+            let write = self.write_index();
+            unsafe { self.unsafe_slices(write, write + 1).0.get_unchecked_mut(0).write(elem) };
+            Ok(WriteHandle { producer: self })
+        } else {
+            Err(elem)
+        }
+    }
+
+    /// Appends an item to the ring buffer and returns the number of items in the ring buffer after that.
+    ///
+    /// If buffer is full returns an `Err` containing the item that hasn't been appended.
+    ///
+    /// *The length is computed from the indices known to `self`, so it may be greater than actual one due to concurring consumer activity.*
+    fn push_len(&mut self, elem: Self::Item) -> Result<usize, Self::Item> {
+        self.try_push(elem)?;
+        Ok(self.occupied_len())
+    }
+
+    /// Appends an item to the ring buffer or calls `on_full` if the buffer is full.
+    ///
+    /// `on_full` is called exactly once and only when the item cannot be pushed due to lack of vacant space.
+    /// It may be used to register interest in vacant space (e.g. arm a timer or a waker) before returning the item back.
+    ///
+    /// If buffer is full returns an `Err` containing the item that hasn't been appended.
+    fn push_or_register<F: FnOnce()>(&mut self, elem: Self::Item, on_full: F) -> Result<(), Self::Item> {
+        self.try_push(elem).inspect_err(|_| on_full())
+    }
+
+    /// Appends an item to the ring buffer retrying until there is a vacant place or `deadline` is reached.
+    ///
+    /// Busy-waits using [`spin_loop`] between attempts and never parks the thread,
+    /// so it is suitable for low-latency code with a strict time budget.
+    ///
+    /// If buffer is still full at `deadline` returns an `Err` containing the item that hasn't been appended.
+    #[cfg(feature = "std")]
+    fn push_deadline(&mut self, mut elem: Self::Item, deadline: Instant) -> Result<(), Self::Item> {
+        loop {
+            match self.try_push(elem) {
+                Ok(()) => return Ok(()),
+                Err(rejected) => elem = rejected,
+            }
+            if Instant::now() >= deadline {
+                return Err(elem);
+            }
+            spin_loop();
+        }
+    }
+
+    /// Resets an item with `reset` and appends it to the ring buffer.
+    ///
+    /// Intended for returning used objects to a pool, so that only sanitized objects can be taken from it.
+    ///
+    /// If buffer is full returns an `Err` containing the item, `reset` isn't called in that case.
+    fn recycle<F: FnMut(&mut Self::Item)>(&mut self, mut elem: Self::Item, mut reset: F) -> Result<(), Self::Item> {
+        if self.is_full() {
+            return Err(elem);
+        }
+        reset(&mut elem);
+        self.try_push(elem)
+    }
+
+    /// Converts a value into an item and appends it to the ring buffer.
+    ///
+    /// If buffer is full returns an `Err` containing the converted item.
+    fn push_into<U: Into<Self::Item>>(&mut self, value: U) -> Result<(), Self::Item> {
+        self.try_push(value.into())
+    }
+
     /// Appends items from an iterator to the ring buffer.
     /// Elements that haven't been added to the ring buffer remain in the iterator.
     ///
@@ -75,10 +240,82 @@ pub trait Producer: Observer {
     /// *Inserted items are committed to the ring buffer all at once in the end,*
     /// *e.g. when buffer is full or iterator has ended.*
     fn push_iter<I: Iterator<Item = Self::Item>>(&mut self, mut iter: I) -> usize {
+        let (left, right) = self.vacant_slices_mut();
+        let (left_hint, right_hint) = match iter.size_hint() {
+            // Exact length is known, so the hinted number of places is filled slice by slice using internal iteration.
+            (lower, Some(upper)) if lower == upper => {
+                let left_hint = usize::min(lower, left.len());
+                (left_hint, usize::min(lower - left_hint, right.len()))
+            }
+            _ => (0, 0),
+        };
+        let mut count = write_iter_fold(&mut left[..left_hint], &mut iter);
+        if count == left_hint {
+            count += write_iter_fold(&mut right[..right_hint], &mut iter);
+        }
+        // The hint may be imprecise, so remaining places are filled item by item.
+        if count == left_hint + right_hint {
+            let (left, right) = (&mut left[left_hint..], &mut right[right_hint..]);
+            count += write_iter(left, &mut iter);
+            if count == left_hint + right_hint + left.len() {
+                count += write_iter(right, &mut iter);
+            }
+        }
+        unsafe { self.advance_write_index(count) };
+        count
+    }
+
+    /// Same as [`Self::push_iter`] but takes the iterator by value and returns it back along with count of items been appended.
+    ///
+    /// The iterator is not advanced beyond the last appended item, so no items are lost when the ring buffer is full.
+    fn push_iter_owned<I: Iterator<Item = Self::Item>>(&mut self, mut iter: I) -> (usize, I) {
+        let count = self.push_iter(&mut iter);
+        (count, iter)
+    }
+
+    /// Same as [`Self::push_iter`] but stops if the consumer is disconnected (see [`Self::is_disconnected`]).
+    ///
+    /// Disconnection is checked before each contiguous run of vacant places is filled,
+    /// so no items are taken from the iterator once the consumer is detected to be dropped.
+    ///
+    /// Returns count of items been appended to the ring buffer,
+    /// or `Err` containing count of items appended before the disconnection has been detected.
+    fn try_push_iter<I: Iterator<Item = Self::Item>>(&mut self, iter: &mut I) -> Result<usize, usize> {
+        let mut count = 0;
+        loop {
+            if self.is_disconnected() {
+                break Err(count);
+            }
+            let run = self.vacant_slices_mut().0;
+            let (len, n) = (run.len(), write_iter(run, iter));
+            unsafe { self.advance_write_index(n) };
+            count += n;
+            if n == 0 || n < len {
+                break Ok(count);
+            }
+        }
+    }
+
+    /// Repeatedly calls `f` and appends returned items to the ring buffer until it returns `None` or the buffer is full.
+    ///
+    /// `f` is called only when there is a vacant place for an item, so no item is lost when the buffer becomes full.
+    ///
+    /// Returns count of items been appended to the ring buffer.
+    fn refill_with<F: FnMut() -> Option<Self::Item>>(&mut self, f: F) -> usize {
+        self.push_iter(iter::from_fn(f))
+    }
+
+    /// Appends items from an iterator to the ring buffer while `pred` returns `true` for them.
+    ///
+    /// The first item for which `pred` returns `false` is not appended and remains in the iterator
+    /// as well as items that haven't been added because the ring buffer is full.
+    ///
+    /// Returns count of items been appended to the ring buffer.
+    fn push_iter_while<I: Iterator<Item = Self::Item>, F: FnMut(&Self::Item) -> bool>(&mut self, iter: &mut Peekable<I>, mut pred: F) -> usize {
         let (left, right) = self.vacant_slices_mut();
         let mut count = 0;
         for place in left.iter_mut().chain(right.iter_mut()) {
-            match iter.next() {
+            match iter.next_if(&mut pred) {
                 Some(elem) => unsafe { place.as_mut_ptr().write(elem) },
                 None => break,
             }
@@ -115,6 +352,63 @@ pub trait Producer: Observer {
         count
     }
 
+    /// Same as [`Self::push_slice`] but checks whether the consumer is disconnected (see [`Self::is_disconnected`]) first.
+    ///
+    /// Returns `None` without appending anything if the consumer is disconnected.
+    fn try_push_slice(&mut self, elems: &[Self::Item]) -> Option<usize>
+    where
+        Self::Item: Copy,
+    {
+        if self.is_disconnected() {
+            None
+        } else {
+            Some(self.push_slice(elems))
+        }
+    }
+
+    /// Moves items from the beginning of the vector to the ring buffer.
+    ///
+    /// As many items as fit into the ring buffer are moved (without cloning), items that don't fit remain in `elems`.
+    ///
+    /// Returns count of items been appended to the ring buffer.
+    #[cfg(feature = "alloc")]
+    fn push_vec(&mut self, elems: &mut Vec<Self::Item>) -> usize {
+        let (left, right) = self.vacant_slices_mut();
+        let len = elems.len();
+        let left_count = usize::min(len, left.len());
+        let right_count = usize::min(len - left_count, right.len());
+        let count = left_count + right_count;
+        unsafe {
+            let src = elems.as_ptr();
+            ptr::copy_nonoverlapping(src, left.as_mut_ptr() as *mut Self::Item, left_count);
+            ptr::copy_nonoverlapping(src.add(left_count), right.as_mut_ptr() as *mut Self::Item, right_count);
+            // Moved items are owned by the ring buffer now, so the remaining ones are shifted to the beginning without dropping.
+            elems.set_len(0);
+            ptr::copy(src.add(count), elems.as_mut_ptr(), len - count);
+            elems.set_len(len - count);
+            self.advance_write_index(count);
+        }
+        count
+    }
+
+    /// Interprets bytes as a sequence of items and appends them to the ring buffer.
+    ///
+    /// If `bytes.len()` is not a multiple of item size then trailing bytes of incomplete item are ignored.
+    /// Bytes are not required to be aligned.
+    ///
+    /// Returns count of whole items been appended to the ring buffer.
+    ///
+    /// *Panics if item is zero-sized.*
+    #[cfg(feature = "bytemuck")]
+    fn push_bytes(&mut self, bytes: &[u8]) -> usize
+    where
+        Self::Item: bytemuck::Pod,
+    {
+        let size = core::mem::size_of::<Self::Item>();
+        assert!(size > 0);
+        self.push_iter(bytes.chunks_exact(size).map(bytemuck::pod_read_unaligned))
+    }
+
     #[cfg(feature = "std")]
     /// Reads at most `count` bytes from `Read` instance and appends them to the ring buffer.
     /// If `count` is `None` then as much as possible bytes will be read.
@@ -122,23 +416,99 @@ pub trait Producer: Observer {
     /// Returns `Ok(n)` if `read` succeeded. `n` is number of bytes been read.
     /// `n == 0` means that either `read` returned zero or ring buffer is full.
     ///
+    /// If the consumer is disconnected (see [`Self::is_disconnected`]) then nothing is read and [`io::ErrorKind::BrokenPipe`] error is returned.
+    ///
     /// If `read` is failed then original error is returned. In this case it is guaranteed that no items was read from the reader.
     /// To achieve this we read only one contiguous slice at once. So this call may read less than `remaining` items in the buffer even if the reader is ready to provide more.
     fn read_from<S: Read>(&mut self, reader: &mut S, count: Option<usize>) -> io::Result<usize>
     where
         Self: Producer<Item = u8>,
     {
+        if self.is_disconnected() {
+            return Err(io::ErrorKind::BrokenPipe.into());
+        }
         let (left, _) = self.vacant_slices_mut();
         let count = cmp::min(count.unwrap_or(left.len()), left.len());
         let left_init = unsafe { slice_assume_init_mut(&mut left[..count]) };
 
         let read_count = reader.read(left_init)?;
-        assert!(read_count <= count);
+        assert!(
+            read_count <= count,
+            "{}reader returned more than buffer length",
+            Label(self.label())
+        );
+        unsafe { self.advance_write_index(read_count) };
+        Ok(read_count)
+    }
+
+    #[cfg(feature = "std")]
+    /// Reads at most `count` bytes from `Read` instance into both vacant slices at once and appends them to the ring buffer.
+    /// If `count` is `None` then as much as possible bytes will be read.
+    ///
+    /// Unlike [`Self::read_from`] it uses [`Read::read_vectored`], so the ring buffer can be filled across its wrap point in a single call.
+    ///
+    /// Returns `Ok(n)` if `read_vectored` succeeded. `n` is number of bytes been read.
+    /// `n == 0` means that either `read_vectored` returned zero or ring buffer is full.
+    ///
+    /// If the consumer is disconnected (see [`Self::is_disconnected`]) then nothing is read and [`io::ErrorKind::BrokenPipe`] error is returned.
+    fn read_from_vectored<S: Read>(&mut self, reader: &mut S, count: Option<usize>) -> io::Result<usize>
+    where
+        Self: Producer<Item = u8>,
+    {
+        if self.is_disconnected() {
+            return Err(io::ErrorKind::BrokenPipe.into());
+        }
+        let (left, right) = self.vacant_slices_mut();
+        let count = cmp::min(count.unwrap_or(usize::MAX), left.len() + right.len());
+        let left_count = cmp::min(count, left.len());
+        let (left_init, right_init) = unsafe {
+            (
+                slice_assume_init_mut(&mut left[..left_count]),
+                slice_assume_init_mut(&mut right[..(count - left_count)]),
+            )
+        };
+
+        let read_count = reader.read_vectored(&mut [IoSliceMut::new(left_init), IoSliceMut::new(right_init)])?;
+        assert!(
+            read_count <= count,
+            "{}reader returned more than buffer length",
+            Label(self.label())
+        );
         unsafe { self.advance_write_index(read_count) };
         Ok(read_count)
     }
 }
 
+/// Handle to an item written by [`Producer::push_ref`].
+///
+/// The item is appended to the ring buffer when the handle is dropped.
+pub struct WriteHandle<'a, P: Producer> {
+    producer: &'a mut P,
+}
+impl<'a, P: Producer> WriteHandle<'a, P> {
+    /// Pointer to the written item that is located at the `write` index.
+    fn slot(&self) -> *mut P::Item {
+        let write = self.producer.write_index();
+        unsafe { self.producer.unsafe_slices(write, write + 1).0.get_unchecked_mut(0).as_mut_ptr() }
+    }
+}
+impl<'a, P: Producer> Deref for WriteHandle<'a, P> {
+    type Target = P::Item;
+    fn deref(&self) -> &P::Item {
+        unsafe { &*self.slot() }
+    }
+}
+impl<'a, P: Producer> DerefMut for WriteHandle<'a, P> {
+    fn deref_mut(&mut self) -> &mut P::Item {
+        unsafe { &mut *self.slot() }
+    }
+}
+impl<'a, P: Producer> Drop for WriteHandle<'a, P> {
+    fn drop(&mut self) {
+        unsafe { self.producer.advance_write_index(1) };
+    }
+}
+
 #[macro_export]
 macro_rules! impl_producer_traits {
     ($type:ident $(< $( $param:tt $( : $first_bound:tt $(+ $next_bound:tt )* )? ),+ >)?) => {
@@ -150,6 +520,9 @@ macro_rules! impl_producer_traits {
         {
             fn write(&mut self, buffer: &[u8]) -> std::io::Result<usize> {
                 use $crate::producer::Producer;
+                if self.is_disconnected() {
+                    return Err(std::io::ErrorKind::BrokenPipe.into());
+                }
                 let n = self.push_slice(buffer);
                 if n == 0 && !buffer.is_empty() {
                     Err(std::io::ErrorKind::WouldBlock.into())
@@ -158,6 +531,8 @@ macro_rules! impl_producer_traits {
                 }
             }
             fn flush(&mut self) -> std::io::Result<()> {
+                use $crate::producer::Producer;
+                self.publish();
                 Ok(())
             }
         }
@@ -166,6 +541,10 @@ macro_rules! impl_producer_traits {
         where
             Self: $crate::traits::Producer<Item = u8>,
         {
+            /// Appends UTF-8 bytes of the string to the ring buffer.
+            ///
+            /// If the ring buffer is full then returns an error, but the bytes that fit are still appended.
+            /// So the contents may end with a part of the string or even a part of a multi-byte character.
             fn write_str(&mut self, s: &str) -> core::fmt::Result {
                 use $crate::producer::Producer;
                 let n = self.push_slice(s.as_bytes());
@@ -191,6 +570,16 @@ macro_rules! delegate_producer {
             $ref(self).advance_write_index(count)
         }
 
+        #[inline]
+        fn publish(&self) {
+            $ref(self).publish()
+        }
+
+        #[inline]
+        fn is_disconnected(&self) -> bool {
+            $ref(self).is_disconnected()
+        }
+
         #[inline]
         fn vacant_slices(&self) -> (&[core::mem::MaybeUninit<Self::Item>], &[core::mem::MaybeUninit<Self::Item>]) {
             $ref(self).vacant_slices()
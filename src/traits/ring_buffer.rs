@@ -13,6 +13,78 @@ use super::{Consumer, Observer, Producer};
 /// without using the space for an extra element in container.
 /// And obviously we cannot store more than `capacity` items in the buffer, so `write - read` modulo `2 * capacity` is not allowed to be greater than `capacity`.
 pub trait RingBuffer: Observer + Consumer + Producer {
+    /// Tells whether read end of the ring buffer is held by a consumer.
+    ///
+    /// Default implementation doesn't track holding and always returns `false`.
+    #[inline]
+    fn read_is_held(&self) -> bool {
+        false
+    }
+    /// Tells whether write end of the ring buffer is held by a producer.
+    ///
+    /// Default implementation doesn't track holding and always returns `false`.
+    #[inline]
+    fn write_is_held(&self) -> bool {
+        false
+    }
+    /// Tells whether read end of the ring buffer has been held by a consumer and then released.
+    ///
+    /// Unlike `!read_is_held()` it is `false` if the ring buffer has never had a consumer (e.g. when it is read directly).
+    /// Holding the read end again resets the state.
+    ///
+    /// Default implementation doesn't track holding and always returns `false`.
+    #[inline]
+    fn read_is_released(&self) -> bool {
+        false
+    }
+
+    /// Marks read end of the ring buffer as held or released by a consumer.
+    ///
+    /// Returns previous state. Default implementation does nothing and returns `false`.
+    ///
+    /// # Safety
+    ///
+    /// Must be called only by consumer wrappers on their creation (with `true`) and destruction (with `false`).
+    #[inline]
+    unsafe fn hold_read(&self, flag: bool) -> bool {
+        let _ = flag;
+        false
+    }
+    /// Marks write end of the ring buffer as held or released by a producer.
+    ///
+    /// Returns previous state. Default implementation does nothing and returns `false`.
+    ///
+    /// # Safety
+    ///
+    /// Must be called only by producer wrappers on their creation (with `true`) and destruction (with `false`).
+    #[inline]
+    unsafe fn hold_write(&self, flag: bool) -> bool {
+        let _ = flag;
+        false
+    }
+
+    /// Rearranges the ring buffer storage so that all items are stored in a single contiguous slice and returns that slice.
+    ///
+    /// If the items are already contiguous then nothing is moved.
+    fn make_contiguous(&mut self) -> &mut [Self::Item] {
+        if !self.is_contiguous() {
+            let (read, len) = (self.read_index() % self.capacity(), self.occupied_len());
+            unsafe {
+                self.unsafe_slices(0, self.capacity().get()).0.rotate_left(read);
+                self.set_read_index(0);
+                self.set_write_index(len);
+            }
+        }
+        self.as_mut_slices().0
+    }
+
+    /// Returns all items as a single contiguous slice.
+    ///
+    /// If the items are wrapped around the end of the storage then they are moved using [`Self::make_contiguous`].
+    fn as_contiguous_slice(&mut self) -> &[Self::Item] {
+        self.make_contiguous()
+    }
+
     /// Pushes an item to the ring buffer overwriting the latest item if the buffer is full.
     ///
     /// Returns overwritten item if overwriting took place.
@@ -53,6 +125,36 @@ pub trait RingBuffer: Observer + Consumer + Producer {
 #[macro_export]
 macro_rules! delegate_ring_buffer {
     ($ref:expr, $mut:expr) => {
+        #[inline]
+        fn read_is_held(&self) -> bool {
+            $ref(self).read_is_held()
+        }
+        #[inline]
+        fn write_is_held(&self) -> bool {
+            $ref(self).write_is_held()
+        }
+        #[inline]
+        fn read_is_released(&self) -> bool {
+            $ref(self).read_is_released()
+        }
+        #[inline]
+        unsafe fn hold_read(&self, flag: bool) -> bool {
+            $ref(self).hold_read(flag)
+        }
+        #[inline]
+        unsafe fn hold_write(&self, flag: bool) -> bool {
+            $ref(self).hold_write(flag)
+        }
+
+        #[inline]
+        fn make_contiguous(&mut self) -> &mut [Self::Item] {
+            $mut(self).make_contiguous()
+        }
+        #[inline]
+        fn as_contiguous_slice(&mut self) -> &[Self::Item] {
+            $mut(self).as_contiguous_slice()
+        }
+
         #[inline]
         fn push_overwrite(&mut self, elem: Self::Item) -> Option<Self::Item> {
             $mut(self).push_overwrite(elem)
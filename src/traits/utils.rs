@@ -1,5 +1,5 @@
 use super::Observer;
-use core::num::NonZeroUsize;
+use core::{fmt, num::NonZeroUsize};
 
 /// Modulus for pointers to item in ring buffer storage.
 ///
@@ -8,3 +8,15 @@ use core::num::NonZeroUsize;
 pub fn modulus(this: &impl Observer) -> NonZeroUsize {
     unsafe { NonZeroUsize::new_unchecked(2 * this.capacity().get()) }
 }
+
+/// Prefix of panic messages which mentions the ring buffer label if any.
+pub struct Label(pub Option<&'static str>);
+
+impl fmt::Display for Label {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(label) => write!(f, "ring buffer `{}`: ", label),
+            None => Ok(()),
+        }
+    }
+}
@@ -1,7 +1,10 @@
-use core::mem::{self, MaybeUninit};
+use core::{
+    mem::{self, MaybeUninit},
+    ops::ControlFlow,
+};
 
 // TODO: Remove on `maybe_uninit_uninit_array` stabilization.
-pub fn uninit_array<T, const N: usize>() -> [MaybeUninit<T>; N] {
+pub const fn uninit_array<T, const N: usize>() -> [MaybeUninit<T>; N] {
     unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() }
 }
 
@@ -26,3 +29,45 @@ pub unsafe fn write_uninit_slice<'a, T: Copy>(dst: &'a mut [T], src: &[MaybeUnin
     dst.copy_from_slice(slice_assume_init_ref(src));
     dst
 }
+
+/// Writes items from iterator to the beginning of `dst` until the iterator ends or `dst` is full.
+///
+/// The iterator isn't advanced when `dst` is full. Returns the number of written items.
+pub fn write_iter<T, I: Iterator<Item = T>>(dst: &mut [MaybeUninit<T>], iter: &mut I) -> usize {
+    let mut count = 0;
+    for place in dst {
+        match iter.next() {
+            Some(item) => {
+                place.write(item);
+            }
+            None => break,
+        }
+        count += 1;
+    }
+    count
+}
+
+/// Same as [`write_iter`] but uses internal iteration ([`Iterator::try_fold`]) of `iter`.
+///
+/// It's faster for iterators that are expensive to step by `next` (e.g. [`core::iter::Chain`]).
+pub fn write_iter_fold<T, I: Iterator<Item = T>>(dst: &mut [MaybeUninit<T>], iter: &mut I) -> usize {
+    let (ptr, len) = (dst.as_mut_ptr(), dst.len());
+    if len == 0 {
+        return 0;
+    }
+    let flow = iter.try_fold(0, |count, item| {
+        // Only fails if a custom `try_fold` of the iterator doesn't stop on `Break`.
+        if count >= len {
+            return ControlFlow::Break(count);
+        }
+        unsafe { ptr.add(count).write(MaybeUninit::new(item)) };
+        if count + 1 < len {
+            ControlFlow::Continue(count + 1)
+        } else {
+            ControlFlow::Break(count + 1)
+        }
+    });
+    match flow {
+        ControlFlow::Continue(count) | ControlFlow::Break(count) => count,
+    }
+}